@@ -59,6 +59,9 @@ async fn main() {
                         ClientState::Opened => {
                             log::info!("client authorized; can start interaction");
                         }
+                        ClientState::Closing => {
+                            log::info!("client is closing")
+                        }
                         ClientState::Closed => {
                             log::warn!("client closed, need to reauthorize it");
                             break;
@@ -122,7 +125,7 @@ async fn main() {
             ClientState::Opened => {
                 log::info!("here we go")
             }
-            ClientState::Closed => {
+            ClientState::Closing | ClientState::Closed => {
                 log::error!("client unexpectedly closed")
             }
             ClientState::Authorizing => {
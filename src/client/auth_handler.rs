@@ -1,3 +1,4 @@
+use crate::errors::{RTDError, RTDResult};
 use crate::types::{
     AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
     AuthorizationStateWaitOtherDeviceConfirmation, AuthorizationStateWaitPassword,
@@ -8,8 +9,18 @@ use std::io;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+/// Data used to log in, returned by [handle_wait_client_auth_data](crate::client::auth_handler::AuthStateHandler::handle_wait_client_auth_data).
+#[derive(Debug, Clone)]
+pub enum ClientAuthData {
+    /// Log in as a user with the phone number
+    Phone(String),
+    /// Log in as a bot with the token received from [@BotFather](https://t.me/botfather)
+    BotToken(String),
+}
+
 /// `AuthStateHandler` trait provides methods that returns data, required for authentication
 /// It allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
+/// Errors returned by handler are sent to [client state channel](crate::client::client::ClientBuilder::with_auth_state_channel).
 #[async_trait]
 pub trait AuthStateHandler {
     /// Interacts with provided link
@@ -23,24 +34,37 @@ pub trait AuthStateHandler {
         );
     }
     /// Returns wait code
-    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> String;
+    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> RTDResult<String>;
     /// Returns database encryption key
     async fn handle_encryption_key(
         &self,
         wait_encryption_key: &AuthorizationStateWaitEncryptionKey,
-    ) -> String;
+    ) -> RTDResult<String>;
     /// Returns password
-    async fn handle_wait_password(&self, wait_password: &AuthorizationStateWaitPassword) -> String;
+    async fn handle_wait_password(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> RTDResult<String>;
+    /// Returns data used to log in, when TDLib waits for phone number.
+    /// Default implementation logs in as a user with phone number returned by [handle_wait_phone_number](crate::client::auth_handler::AuthStateHandler::handle_wait_phone_number).
+    async fn handle_wait_client_auth_data(
+        &self,
+        wait_phone_number: &AuthorizationStateWaitPhoneNumber,
+    ) -> RTDResult<ClientAuthData> {
+        Ok(ClientAuthData::Phone(
+            self.handle_wait_phone_number(wait_phone_number).await?,
+        ))
+    }
     /// Returns phone number
     async fn handle_wait_phone_number(
         &self,
         wait_phone_number: &AuthorizationStateWaitPhoneNumber,
-    ) -> String;
+    ) -> RTDResult<String>;
     /// Returns first_name and second_name
     async fn handle_wait_registration(
         &self,
         wait_registration: &AuthorizationStateWaitRegistration,
-    ) -> (String, String);
+    ) -> RTDResult<(String, String)>;
 }
 
 /// Provides minimal implementation of `AuthStateHandler`.
@@ -59,18 +83,16 @@ impl ConsoleAuthStateHandler {
         Self
     }
 
-    fn wait_input() -> String {
+    fn wait_input() -> RTDResult<String> {
         let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => input.trim().to_string(),
-            Err(e) => panic!("Can not get input value: {:?}", e),
-        }
+        io::stdin().read_line(&mut input)?;
+        Ok(input.trim().to_string())
     }
 }
 
 #[async_trait]
 impl AuthStateHandler for ConsoleAuthStateHandler {
-    async fn handle_wait_code(&self, _wait_code: &AuthorizationStateWaitCode) -> String {
+    async fn handle_wait_code(&self, _wait_code: &AuthorizationStateWaitCode) -> RTDResult<String> {
         println!("waiting for auth code");
         ConsoleAuthStateHandler::wait_input()
     }
@@ -78,7 +100,7 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
     async fn handle_encryption_key(
         &self,
         _wait_encryption_key: &AuthorizationStateWaitEncryptionKey,
-    ) -> String {
+    ) -> RTDResult<String> {
         println!("waiting for encryption key");
         ConsoleAuthStateHandler::wait_input()
     }
@@ -86,7 +108,7 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
     async fn handle_wait_password(
         &self,
         _wait_password: &AuthorizationStateWaitPassword,
-    ) -> String {
+    ) -> RTDResult<String> {
         println!("waiting for password");
         ConsoleAuthStateHandler::wait_input()
     }
@@ -94,7 +116,7 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
     async fn handle_wait_phone_number(
         &self,
         _wait_phone_number: &AuthorizationStateWaitPhoneNumber,
-    ) -> String {
+    ) -> RTDResult<String> {
         println!("waiting for phone number");
         ConsoleAuthStateHandler::wait_input()
     }
@@ -102,12 +124,12 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
     async fn handle_wait_registration(
         &self,
         _wait_registration: &AuthorizationStateWaitRegistration,
-    ) -> (String, String) {
+    ) -> RTDResult<(String, String)> {
         loop {
             println!("waiting for first_name and second_name separated by comma");
-            let inp: String = ConsoleAuthStateHandler::wait_input();
+            let inp: String = ConsoleAuthStateHandler::wait_input()?;
             if let Some((f, l)) = split_string(inp, ',') {
-                return (f, l);
+                return Ok((f, l));
             }
         }
     }
@@ -126,32 +148,41 @@ impl SignalAuthStateHandler {
         }
     }
 
-    async fn wait_signal(&self) -> String {
+    async fn wait_signal(&self) -> RTDResult<String> {
         let mut guard = self.rec.lock().await;
-        guard.recv().await.expect("no signals received")
+        guard
+            .recv()
+            .await
+            .ok_or(RTDError::Internal("no signals received"))
     }
 }
 
 #[async_trait]
 impl AuthStateHandler for SignalAuthStateHandler {
-    async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> String {
+    async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> RTDResult<String> {
         log::info!("waiting for auth code");
         self.wait_signal().await
     }
 
-    async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
+    async fn handle_encryption_key(
+        &self,
+        _: &AuthorizationStateWaitEncryptionKey,
+    ) -> RTDResult<String> {
         log::info!("waiting for encryption key");
-        let f = self.wait_signal().await;
+        let f = self.wait_signal().await?;
         log::info!("get encryption key");
-        f
+        Ok(f)
     }
 
-    async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> String {
+    async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> RTDResult<String> {
         log::info!("waiting for password");
         self.wait_signal().await
     }
 
-    async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
+    async fn handle_wait_phone_number(
+        &self,
+        _: &AuthorizationStateWaitPhoneNumber,
+    ) -> RTDResult<String> {
         log::info!("waiting for phone number");
         self.wait_signal().await
     }
@@ -159,17 +190,74 @@ impl AuthStateHandler for SignalAuthStateHandler {
     async fn handle_wait_registration(
         &self,
         _: &AuthorizationStateWaitRegistration,
-    ) -> (String, String) {
+    ) -> RTDResult<(String, String)> {
         loop {
             log::info!("waiting for first name and last name separated by comma");
-            let inp = self.wait_signal().await;
+            let inp = self.wait_signal().await?;
             if let Some((f, l)) = split_string(inp, ',') {
-                return (f, l);
+                return Ok((f, l));
             }
         }
     }
 }
 
+/// Logs in as a bot with the token.
+/// Bots never receive code, password or registration requests, so corresponding methods just return errors.
+#[derive(Debug, Clone)]
+pub struct BotAuthStateHandler {
+    token: String,
+}
+
+impl BotAuthStateHandler {
+    pub fn new<T: Into<String>>(token: T) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+const NOT_SUPPORTED_FOR_BOTS: RTDError =
+    RTDError::BadRequest("authorization state is not expected for bots");
+
+#[async_trait]
+impl AuthStateHandler for BotAuthStateHandler {
+    async fn handle_wait_client_auth_data(
+        &self,
+        _: &AuthorizationStateWaitPhoneNumber,
+    ) -> RTDResult<ClientAuthData> {
+        Ok(ClientAuthData::BotToken(self.token.clone()))
+    }
+
+    async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> RTDResult<String> {
+        Err(NOT_SUPPORTED_FOR_BOTS)
+    }
+
+    async fn handle_encryption_key(
+        &self,
+        _: &AuthorizationStateWaitEncryptionKey,
+    ) -> RTDResult<String> {
+        Ok(String::new())
+    }
+
+    async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> RTDResult<String> {
+        Err(NOT_SUPPORTED_FOR_BOTS)
+    }
+
+    async fn handle_wait_phone_number(
+        &self,
+        _: &AuthorizationStateWaitPhoneNumber,
+    ) -> RTDResult<String> {
+        Err(NOT_SUPPORTED_FOR_BOTS)
+    }
+
+    async fn handle_wait_registration(
+        &self,
+        _: &AuthorizationStateWaitRegistration,
+    ) -> RTDResult<(String, String)> {
+        Err(NOT_SUPPORTED_FOR_BOTS)
+    }
+}
+
 fn split_string(input: String, sep: char) -> Option<(String, String)> {
     let found: Vec<&str> = input.splitn(2, |c| c == sep).collect();
     if let 2 = found.len() {
@@ -0,0 +1,115 @@
+use crate::types::{OptionValue, Update};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tokio::sync::broadcast;
+
+const UNREAD_COUNT_CHANNEL_SIZE: usize = 100;
+
+/// Client-side state maintained from received updates.
+#[derive(Debug)]
+pub(crate) struct ClientCache {
+    unread_counts: RwLock<HashMap<i64, i32>>,
+    unread_count_sender: broadcast::Sender<(i64, i32)>,
+    options: RwLock<HashMap<String, OptionValue>>,
+    supergroup_member_counts: RwLock<HashMap<i32, i32>>,
+    basic_group_member_counts: RwLock<HashMap<i32, i32>>,
+}
+
+impl Default for ClientCache {
+    fn default() -> Self {
+        let (unread_count_sender, _) = broadcast::channel(UNREAD_COUNT_CHANNEL_SIZE);
+        Self {
+            unread_counts: RwLock::new(HashMap::new()),
+            unread_count_sender,
+            options: RwLock::new(HashMap::new()),
+            supergroup_member_counts: RwLock::new(HashMap::new()),
+            basic_group_member_counts: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl ClientCache {
+    pub fn handle_update(&self, update: &Update) {
+        match update {
+            Update::NewChat(new_chat) => {
+                self.set_unread_count(new_chat.chat().id(), new_chat.chat().unread_count())
+            }
+            Update::ChatReadInbox(read_inbox) => {
+                self.set_unread_count(read_inbox.chat_id(), read_inbox.unread_count())
+            }
+            Update::Option(option) => {
+                let mut options = self.options.write().unwrap();
+                match option.value() {
+                    OptionValue::Empty(_) => options.remove(option.name()),
+                    value => options.insert(option.name().clone(), value.clone()),
+                };
+            }
+            Update::SupergroupFullInfo(full_info) => self.set_supergroup_member_count(
+                full_info.supergroup_id(),
+                full_info.supergroup_full_info().member_count(),
+            ),
+            Update::BasicGroupFullInfo(full_info) => self.set_basic_group_member_count(
+                full_info.basic_group_id(),
+                full_info.basic_group_full_info().members().len() as i32,
+            ),
+            _ => {}
+        }
+    }
+
+    pub fn supergroup_member_count(&self, supergroup_id: i32) -> Option<i32> {
+        self.supergroup_member_counts
+            .read()
+            .unwrap()
+            .get(&supergroup_id)
+            .copied()
+    }
+
+    pub fn set_supergroup_member_count(&self, supergroup_id: i32, member_count: i32) {
+        self.supergroup_member_counts
+            .write()
+            .unwrap()
+            .insert(supergroup_id, member_count);
+    }
+
+    pub fn basic_group_member_count(&self, basic_group_id: i32) -> Option<i32> {
+        self.basic_group_member_counts
+            .read()
+            .unwrap()
+            .get(&basic_group_id)
+            .copied()
+    }
+
+    pub fn set_basic_group_member_count(&self, basic_group_id: i32, member_count: i32) {
+        self.basic_group_member_counts
+            .write()
+            .unwrap()
+            .insert(basic_group_id, member_count);
+    }
+
+    pub fn option_integer(&self, name: &str) -> Option<i64> {
+        match self.options.read().unwrap().get(name) {
+            Some(OptionValue::Integer(v)) => Some(v.value()),
+            _ => None,
+        }
+    }
+
+    pub fn unread_count(&self, chat_id: i64) -> Option<i32> {
+        self.unread_counts.read().unwrap().get(&chat_id).copied()
+    }
+
+    pub fn subscribe_unread_counts(&self) -> broadcast::Receiver<(i64, i32)> {
+        self.unread_count_sender.subscribe()
+    }
+
+    fn set_unread_count(&self, chat_id: i64, unread_count: i32) {
+        let previous = self
+            .unread_counts
+            .write()
+            .unwrap()
+            .insert(chat_id, unread_count);
+        if previous != Some(unread_count) {
+            // error means there are no subscribers, so nothing to do
+            let _ = self.unread_count_sender.send((chat_id, unread_count));
+        }
+    }
+}
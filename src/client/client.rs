@@ -1,14 +1,26 @@
 use super::{
+    cache::ClientCache,
+    history::RequestHistory,
+    metrics::Metrics,
     observer::OBSERVER,
+    retry::RetryPolicy,
+    sender::{ExtraGeneratorFn, RequestSender},
     tdlib_client::{TdJson, TdLibClient},
+    worker::{UpdateSubscriber, UpdatesDelivery},
 };
 use crate::{
     errors::{RTDError, RTDResult},
     types::*,
 };
-use tokio::sync::mpsc;
-
-const CLOSED_RECEIVER_ERROR: RTDError = RTDError::Internal("receiver already closed");
+use futures::{Future, Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, Mutex, Semaphore};
+
+pub(crate) const CLOSED_RECEIVER_MESSAGE: &str = "receiver already closed";
+const CLOSED_RECEIVER_ERROR: RTDError = RTDError::Internal(CLOSED_RECEIVER_MESSAGE);
 const INVALID_RESPONSE_ERROR: RTDError = RTDError::Internal("receive invalid response");
 const NO_EXTRA: RTDError =
     RTDError::Internal("invalid tdlib response type, not have `extra` field");
@@ -19,12 +31,454 @@ const CLIENT_NOT_AUTHORIZED: RTDError = RTDError::Internal("client not authorize
 pub enum ClientState {
     /// Client opened. You can start interaction
     Opened,
+    /// Client is closing, [Closed](ClientState::Closed) follows when TDLib completes it
+    Closing,
     /// Client closed. You must reopen it if you want to interact with TDLib
     Closed,
     /// Client not authorizde yet
     Authorizing,
 }
 
+/// Options loaded by [warm_up](Client::warm_up).
+const WARM_UP_OPTIONS: &[&str] = &[
+    "my_id",
+    "message_text_length_max",
+    "message_caption_length_max",
+    "forwarded_message_count_max",
+    "basic_group_size_max",
+    "supergroup_size_max",
+];
+
+fn input_message_text(text: FormattedText) -> InputMessageContent {
+    InputMessageContent::InputMessageText(InputMessageText::builder().text(text).build())
+}
+
+// Splits text into parts of at most `max_length` UTF-16 code units, preferring line and word boundaries.
+fn split_text(text: &str, max_length: usize) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while rest.encode_utf16().count() > max_length {
+        let mut end = 0;
+        let mut length = 0;
+        for (i, c) in rest.char_indices() {
+            if length + c.len_utf16() > max_length {
+                end = i;
+                break;
+            }
+            length += c.len_utf16();
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let split_at = rest[..end]
+            .rfind('\n')
+            .or_else(|| rest[..end].rfind(' '))
+            .filter(|&i| i > 0)
+            .map_or(end, |i| i + 1);
+        parts.push(&rest[..split_at]);
+        rest = &rest[split_at..];
+    }
+    if !rest.is_empty() {
+        parts.push(rest);
+    }
+    parts
+}
+
+/// Used by [send_text_message](Client::send_text_message) until `message_text_length_max` option received from TDLib.
+const MESSAGE_TEXT_LENGTH_MAX_DEFAULT: i64 = 4096;
+
+/// Number of updates kept for lagging [broadcast](Client::subscribe_broadcast) receivers.
+const UPDATES_BROADCAST_CAPACITY: usize = 1000;
+
+/// Delays accepted by [set_slow_mode](Client::set_slow_mode).
+const SLOW_MODE_DELAYS: &[i32] = &[0, 10, 30, 60, 300, 900, 3600];
+
+/// Maximum number of messages TDLib returns per request.
+const MESSAGES_PAGE_SIZE_MAX: i32 = 100;
+
+/// Number of chats requested by [get_all_chats](Client::get_all_chats) at once.
+const CHATS_PAGE_SIZE: i32 = 100;
+
+/// How long [get_chat_loaded](Client::get_chat_loaded) waits for the last message of the chat.
+const CHAT_LOAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Commonly-needed context of the authorized user, returned by [warm_up](Client::warm_up).
+#[derive(Debug, Clone)]
+pub struct SelfContext {
+    user: User,
+    my_id: i32,
+    options: HashMap<String, OptionValue>,
+}
+
+impl SelfContext {
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn my_id(&self) -> i32 {
+        self.my_id
+    }
+
+    pub fn options(&self) -> &HashMap<String, OptionValue> {
+        &self.options
+    }
+
+    /// Returns value of an integer option, if it was loaded.
+    pub fn option_integer(&self, name: &str) -> Option<i64> {
+        match self.options.get(name) {
+            Some(OptionValue::Integer(v)) => Some(v.value()),
+            _ => None,
+        }
+    }
+}
+
+/// Progress of the file download, yielded by [download_file_stream](Client::download_file_stream).
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    file: File,
+}
+
+impl DownloadProgress {
+    /// Current state of the file.
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    /// Number of bytes downloaded so far.
+    pub fn downloaded_size(&self) -> i32 {
+        self.file.local().downloaded_size()
+    }
+
+    /// Size of the file in bytes, or its approximate size if the exact one is unknown; 0 if both are unknown.
+    pub fn expected_size(&self) -> i32 {
+        match self.file.size() {
+            0 => self.file.expected_size(),
+            size => size,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.file.local().is_downloading_completed()
+    }
+
+    /// Local path to the downloaded file, available when download is completed.
+    pub fn path(&self) -> Option<&str> {
+        match self.is_completed() {
+            true => Some(self.file.local().path()),
+            false => None,
+        }
+    }
+}
+
+/// Parameters of [optimize_storage_with_options](Client::optimize_storage_with_options).
+/// Limits which are not specified use TDLib defaults.
+#[derive(Debug, Clone, Default)]
+pub struct StorageOptimizeOptions {
+    size: Option<i64>,
+    ttl: Option<i32>,
+    count: Option<i32>,
+    immunity_delay: Option<i32>,
+    file_types: Vec<FileType>,
+    chat_ids: Vec<i64>,
+    exclude_chat_ids: Vec<i64>,
+    return_deleted_file_statistics: bool,
+    chat_limit: i32,
+}
+
+impl StorageOptimizeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit on the total size of files after deletion, in bytes.
+    pub fn with_size(mut self, size: i64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Files which were not accessed for the specified number of seconds are deleted.
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Limit on the total count of files after deletion.
+    pub fn with_count(mut self, count: i32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// The amount of time after the creation of a file during which it can't be deleted, in seconds.
+    pub fn with_immunity_delay(mut self, immunity_delay: i32) -> Self {
+        self.immunity_delay = Some(immunity_delay);
+        self
+    }
+
+    /// Only files with the given types are considered.
+    pub fn with_file_types(mut self, file_types: Vec<FileType>) -> Self {
+        self.file_types = file_types;
+        self
+    }
+
+    /// Only files from the given chats are considered.
+    pub fn with_chat_ids(mut self, chat_ids: Vec<i64>) -> Self {
+        self.chat_ids = chat_ids;
+        self
+    }
+
+    /// Files from the given chats are not deleted.
+    pub fn with_exclude_chat_ids(mut self, exclude_chat_ids: Vec<i64>) -> Self {
+        self.exclude_chat_ids = exclude_chat_ids;
+        self
+    }
+
+    /// Return statistics about deleted files instead of the whole storage usage statistics.
+    pub fn with_deleted_file_statistics(mut self, return_deleted_file_statistics: bool) -> Self {
+        self.return_deleted_file_statistics = return_deleted_file_statistics;
+        self
+    }
+
+    /// Number of chats with the largest storage usage for which separate statistics are returned.
+    pub fn with_chat_limit(mut self, chat_limit: i32) -> Self {
+        self.chat_limit = chat_limit;
+        self
+    }
+
+    fn to_request(&self) -> OptimizeStorage {
+        // TDLib uses -1 for the default limit; zero means "delete everything"
+        OptimizeStorage::builder()
+            .size(self.size.unwrap_or(-1))
+            .ttl(self.ttl.unwrap_or(-1))
+            .count(self.count.unwrap_or(-1))
+            .immunity_delay(self.immunity_delay.unwrap_or(-1))
+            .file_types(self.file_types.clone())
+            .chat_ids(self.chat_ids.clone())
+            .exclude_chat_ids(self.exclude_chat_ids.clone())
+            .return_deleted_file_statistics(self.return_deleted_file_statistics)
+            .chat_limit(self.chat_limit)
+            .build()
+    }
+}
+
+/// Result of [join_by_invite_link](Client::join_by_invite_link).
+#[derive(Debug, Clone)]
+pub enum JoinResult {
+    /// Chat joined successfully
+    Joined(Chat),
+    /// User is already a member of the chat
+    AlreadyMember,
+    /// Invite link expired or its usage limit exceeded
+    LinkExpired,
+    /// Invite link is invalid
+    LinkInvalid,
+    /// User is banned in the chat
+    Banned,
+    /// Chat requires administrator approval, join request sent
+    RequestSent,
+    /// User joined too many channels and supergroups
+    TooManyChannels,
+}
+
+impl JoinResult {
+    fn from_error(error: &crate::errors::TDLibError) -> Option<Self> {
+        let message = error.message();
+        if message.contains("USER_ALREADY_PARTICIPANT") {
+            Some(JoinResult::AlreadyMember)
+        } else if message.contains("INVITE_HASH_EXPIRED") || message.contains("USERS_TOO_MUCH") {
+            Some(JoinResult::LinkExpired)
+        } else if message.contains("INVITE_HASH_INVALID") || message.contains("INVITE_HASH_EMPTY") {
+            Some(JoinResult::LinkInvalid)
+        } else if message.contains("USER_BANNED_IN_CHANNEL") || message.contains("CHANNEL_PRIVATE")
+        {
+            Some(JoinResult::Banned)
+        } else if message.contains("INVITE_REQUEST_SENT") {
+            Some(JoinResult::RequestSent)
+        } else if message.contains("CHANNELS_TOO_MUCH") {
+            Some(JoinResult::TooManyChannels)
+        } else {
+            None
+        }
+    }
+}
+
+/// Error of [transfer_ownership](Client::transfer_ownership).
+#[derive(Debug)]
+pub enum TransferOwnershipError {
+    /// Two-step verification password must be set up before transferring ownership
+    PasswordNeeded,
+    /// Specified password is invalid
+    PasswordInvalid,
+    /// Two-step verification password was changed recently, try again later
+    PasswordTooFresh,
+    /// Current session was created recently, try again later
+    SessionTooFresh,
+    /// Any other error
+    Other(RTDError),
+}
+
+impl From<RTDError> for TransferOwnershipError {
+    fn from(err: RTDError) -> Self {
+        if let RTDError::TDLibError(error) = &err {
+            let message = error.message();
+            if message.contains("PASSWORD_MISSING") {
+                return TransferOwnershipError::PasswordNeeded;
+            } else if message.contains("PASSWORD_HASH_INVALID") {
+                return TransferOwnershipError::PasswordInvalid;
+            } else if message.contains("PASSWORD_TOO_FRESH") {
+                return TransferOwnershipError::PasswordTooFresh;
+            } else if message.contains("SESSION_TOO_FRESH") {
+                return TransferOwnershipError::SessionTooFresh;
+            }
+        }
+        TransferOwnershipError::Other(err)
+    }
+}
+
+impl std::fmt::Display for TransferOwnershipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TransferOwnershipError::PasswordNeeded => write!(f, "password is not set up"),
+            TransferOwnershipError::PasswordInvalid => write!(f, "password is invalid"),
+            TransferOwnershipError::PasswordTooFresh => write!(f, "password changed recently"),
+            TransferOwnershipError::SessionTooFresh => write!(f, "session created recently"),
+            TransferOwnershipError::Other(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for TransferOwnershipError {}
+
+/// Message sent with [send_text_message](Client::send_text_message). Allows to manage the message without keeping its identifiers.
+#[derive(Debug, Clone)]
+pub struct SentMessage<S>
+where
+    S: TdLibClient + Clone,
+{
+    client: Client<S>,
+    message: Message,
+}
+
+impl<S> SentMessage<S>
+where
+    S: TdLibClient + Clone,
+{
+    /// Returns the sent message.
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+
+    /// Replaces text of the message.
+    pub async fn edit_text<T: AsRef<str>>(&self, text: T) -> RTDResult<Message> {
+        self.client
+            .edit_message_text(
+                EditMessageText::builder()
+                    .chat_id(self.message.chat_id())
+                    .message_id(self.message.id())
+                    .input_message_content(input_message_text(
+                        FormattedText::builder().text(text).build(),
+                    ))
+                    .build(),
+            )
+            .await
+    }
+
+    /// Deletes the message for all chat members.
+    pub async fn delete(&self) -> RTDResult<()> {
+        self.client
+            .delete_messages(
+                DeleteMessages::builder()
+                    .chat_id(self.message.chat_id())
+                    .message_ids(vec![self.message.id()])
+                    .revoke(true)
+                    .build(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Pins the message in the chat.
+    pub async fn pin(&self, disable_notification: bool) -> RTDResult<()> {
+        self.client
+            .pin_chat_message(
+                PinChatMessage::builder()
+                    .chat_id(self.message.chat_id())
+                    .message_id(self.message.id())
+                    .disable_notification(disable_notification)
+                    .build(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Keeps the chat opened while alive, see [open_chat_guarded](Client::open_chat_guarded).
+/// Chat is closed in background when the guard dropped, use [close](ChatGuard::close) to wait for closing.
+#[derive(Debug)]
+pub struct ChatGuard<S>
+where
+    S: TdLibClient + Clone + Send + Sync + 'static,
+{
+    client: Option<Client<S>>,
+    chat_id: i64,
+}
+
+impl<S> ChatGuard<S>
+where
+    S: TdLibClient + Clone + Send + Sync + 'static,
+{
+    /// Returns identifier of the opened chat.
+    pub fn chat_id(&self) -> i64 {
+        self.chat_id
+    }
+
+    /// Closes the chat.
+    pub async fn close(mut self) -> RTDResult<()> {
+        match self.client.take() {
+            None => Ok(()),
+            Some(client) => {
+                client
+                    .close_chat(CloseChat::builder().chat_id(self.chat_id).build())
+                    .await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<S> Drop for ChatGuard<S>
+where
+    S: TdLibClient + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            let chat_id = self.chat_id;
+            match tokio::runtime::Handle::try_current() {
+                Err(_) => log::warn!("chat {} not closed: no runtime available", chat_id),
+                Ok(handle) => {
+                    handle.spawn(async move {
+                        if let Err(err) = client
+                            .close_chat(CloseChat::builder().chat_id(chat_id).build())
+                            .await
+                        {
+                            log::error!("can't close chat {}: {}", chat_id, err)
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+// Hook adjusting TDLib parameters before they're sent, wrapped to keep Client debuggable.
+#[derive(Clone)]
+struct ParametersFn(Arc<dyn Fn(&mut TdlibParameters) + Send + Sync>);
+
+impl std::fmt::Debug for ParametersFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ParametersFn")
+    }
+}
+
 /// Struct stores all methods which you can call to interact with Telegram, such as:
 /// [send_message](Api::send_message), [download_file](Api::download_file), [search_chats](Api::search_chats) and so on.
 #[derive(Clone, Debug)]
@@ -32,12 +486,21 @@ pub struct Client<S>
 where
     S: TdLibClient + Clone,
 {
-    tdlib_client: S,
-    client_id: Option<i32>,
+    tdlib_client: RequestSender<S>,
+    // shared between clones, so all of them follow the client when it's reconnected by the worker
+    client_id: Arc<std::sync::RwLock<Option<i32>>>,
     is_started: bool,
     updates_sender: Option<mpsc::Sender<Box<Update>>>,
     tdlib_parameters: TdlibParameters,
     auth_state_channel_size: Option<usize>,
+    self_context: Arc<Mutex<Option<SelfContext>>>,
+    cache: Arc<ClientCache>,
+    updates_broadcast: broadcast::Sender<Box<Update>>,
+    downloads_semaphore: Option<Arc<Semaphore>>,
+    parameters_fn: Option<ParametersFn>,
+    qr_login: bool,
+    update_subscribers: Arc<std::sync::Mutex<Vec<UpdateSubscriber>>>,
+    dropped_updates: Arc<AtomicU64>,
 }
 
 impl<S> Client<S>
@@ -48,42 +511,199 @@ where
         self.auth_state_channel_size
     }
 
-    pub(crate) fn tdlib_parameters(&self) -> &TdlibParameters {
-        &self.tdlib_parameters
+    pub(crate) fn qr_login(&self) -> bool {
+        self.qr_login
+    }
+
+    // Returns parameters adjusted with hook specified by ClientBuilder::with_parameters_fn
+    pub(crate) fn tdlib_parameters_to_send(&self) -> TdlibParameters {
+        let mut tdlib_parameters = self.tdlib_parameters.clone();
+        if let Some(parameters_fn) = &self.parameters_fn {
+            (parameters_fn.0)(&mut tdlib_parameters);
+        }
+        tdlib_parameters
     }
 
     pub fn get_tdlib_client(&self) -> S {
-        self.tdlib_client.clone()
+        self.tdlib_client.tdlib_client().clone()
     }
 
     pub(crate) fn get_client_id(&self) -> RTDResult<i32> {
-        match self.client_id {
+        match *self.client_id.read().unwrap() {
             Some(client_id) => Ok(client_id),
             None => Err(CLIENT_NOT_AUTHORIZED),
         }
     }
 
     pub(crate) fn take_client_id(&mut self) -> RTDResult<i32> {
-        match self.client_id.take() {
+        match self.client_id.write().unwrap().take() {
             Some(client_id) => Ok(client_id),
             None => Err(CLIENT_NOT_AUTHORIZED),
         }
     }
 
     pub(crate) fn set_client_id(&mut self, client_id: i32) -> RTDResult<()> {
-        match self.client_id {
+        let mut current = self.client_id.write().unwrap();
+        match *current {
             Some(_) => Err(RTDError::BadRequest("client already authorized")),
             None => {
-                self.client_id = Some(client_id);
+                *current = Some(client_id);
                 self.is_started = true;
                 Ok(())
             }
         }
     }
 
+    // Replaces identifier of already bound client, used when the worker recreates TDLib client
+    pub(crate) fn replace_client_id(&self, client_id: i32) {
+        *self.client_id.write().unwrap() = Some(client_id);
+    }
+
     pub(crate) fn updates_sender(&self) -> &Option<mpsc::Sender<Box<Update>>> {
         &self.updates_sender
     }
+
+    pub(crate) fn cache(&self) -> &ClientCache {
+        &self.cache
+    }
+
+    pub(crate) fn broadcast_update(&self, update: &Update) {
+        if self.updates_broadcast.receiver_count() > 0 {
+            // receivers may be dropped concurrently, nothing to do in this case
+            let _ = self.updates_broadcast.send(Box::new(update.clone()));
+        }
+    }
+
+    pub(crate) fn set_request_history(&mut self, request_history: Option<Arc<RequestHistory>>) {
+        self.tdlib_client.set_request_history(request_history);
+    }
+
+    pub(crate) fn set_max_concurrent_downloads(&mut self, max_concurrent_downloads: Option<usize>) {
+        self.downloads_semaphore = max_concurrent_downloads.map(|n| Arc::new(Semaphore::new(n)));
+    }
+
+    pub(crate) fn set_shutdown_flag(&mut self, shutdown_flag: Arc<AtomicBool>) {
+        self.tdlib_client.set_shutdown_flag(shutdown_flag);
+    }
+
+    pub(crate) fn set_metrics(&mut self, metrics: Option<Metrics>) {
+        self.tdlib_client.set_metrics(metrics);
+    }
+
+    /// Executes TDLib method synchronously, the response is returned immediately, without sending the request through the worker.
+    /// Only methods which don't require network access can be executed this way, see [SyncFunction].
+    pub fn execute<F: SyncFunction>(&self, function: F) -> RTDResult<F::Response> {
+        let response = self
+            .tdlib_client
+            .tdlib_client()
+            .execute(function)?
+            .ok_or(RTDError::Internal("no response to synchronous request"))?;
+        let value: serde_json::Value = serde_json::from_str(&response)?;
+        match value.get("@type").and_then(|t| t.as_str()) {
+            Some("error") => Err(RTDError::TDLibError(serde_json::from_value(value)?)),
+            _ => Ok(serde_json::from_value(value)?),
+        }
+    }
+
+    /// Returns new receiver of all updates of the client.
+    /// Unlike [updates sender](crate::client::client::ClientBuilder::with_updates_sender), each receiver gets every update, so multiple independent consumers can observe the same stream.
+    /// Receiver which can't keep up loses oldest updates, see [RecvError::Lagged](tokio::sync::broadcast::error::RecvError::Lagged).
+    pub fn subscribe_broadcast(&self) -> broadcast::Receiver<Box<Update>> {
+        self.updates_broadcast.subscribe()
+    }
+
+    /// Registers new receiver of all updates of the client, in addition to [updates sender](crate::client::client::ClientBuilder::with_updates_sender).
+    /// Worker doesn't wait for the subscriber, so it can't hold up other clients: when the channel is full, worker's [DropPolicy](crate::client::worker::DropPolicy) is applied
+    /// and dropped updates are counted in [dropped_updates](crate::client::worker::Worker::dropped_updates). Subscriber is removed when the receiver dropped.
+    pub fn add_update_subscriber(&self, channel_size: usize) -> mpsc::Receiver<Box<Update>> {
+        let (sender, receiver) = mpsc::channel(channel_size);
+        self.update_subscribers
+            .lock()
+            .unwrap()
+            .push(UpdateSubscriber {
+                sender,
+                delivery: Arc::new(UpdatesDelivery::new(self.dropped_updates.clone())),
+            });
+        receiver
+    }
+
+    // Returns all active update subscribers, forgetting closed ones
+    pub(crate) fn update_subscribers(&self) -> Vec<UpdateSubscriber> {
+        let mut subscribers = self.update_subscribers.lock().unwrap();
+        subscribers.retain(|subscriber| !subscriber.sender.is_closed());
+        subscribers.clone()
+    }
+
+    // Counter of updates not delivered to the client's updates sender or update subscribers
+    pub(crate) fn dropped_updates(&self) -> Arc<AtomicU64> {
+        self.dropped_updates.clone()
+    }
+
+    /// Returns number of updates sent to [updates sender](crate::client::client::ClientBuilder::with_updates_sender) but not received yet.
+    /// Returns 0 if updates sender not specified.
+    pub fn pending_updates(&self) -> usize {
+        match &self.updates_sender {
+            None => 0,
+            Some(sender) => sender.max_capacity() - sender.capacity(),
+        }
+    }
+
+    /// Calls `func` with the client until it succeeds, returns non-retryable error or attempts specified by `policy` exhausted.
+    /// Waits between attempts, see [RetryPolicy](crate::client::RetryPolicy). Example:
+    /// `client.send_with_retry(|c| c.search_messages(request.clone()), RetryPolicy::default())`
+    pub async fn send_with_retry<'a, F, Fut, T>(
+        &'a self,
+        func: F,
+        policy: RetryPolicy,
+    ) -> RTDResult<T>
+    where
+        F: Fn(&'a Self) -> Fut,
+        Fut: Future<Output = RTDResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match func(self).await {
+                Ok(result) => return Ok(result),
+                Err(err) if attempt < policy.max_attempts() && policy.is_retryable(&err) => {
+                    let delay = policy.delay(attempt, &err);
+                    log::debug!("request failed: {}, retrying in {:?}", err, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+// Converts broadcast receiver to stream, skipping values lost by a slow consumer.
+fn broadcast_stream<T: Clone + Send + 'static>(
+    receiver: broadcast::Receiver<T>,
+) -> impl Stream<Item = T> {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(v) => return Some((v, receiver)),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("stream consumer lagged, {} values skipped", skipped)
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+// Determines that both values refer to the same chat list
+fn is_same_chat_list(list: &ChatList, other: &ChatList) -> bool {
+    match (list, other) {
+        (ChatList::Main(_), ChatList::Main(_)) | (ChatList::Archive(_), ChatList::Archive(_)) => {
+            true
+        }
+        (ChatList::Filter(filter), ChatList::Filter(other)) => {
+            filter.chat_filter_id() == other.chat_filter_id()
+        }
+        _ => false,
+    }
 }
 
 #[derive(Debug)]
@@ -95,6 +715,11 @@ where
     tdlib_parameters: Option<TdlibParameters>,
     tdlib_client: R,
     auth_state_channel_size: Option<usize>,
+    parameters_fn: Option<ParametersFn>,
+    extra_generator: Option<ExtraGeneratorFn>,
+    qr_login: bool,
+    request_timeout: Option<Duration>,
+    updates_channel_capacity: usize,
 }
 
 impl Default for ClientBuilder<TdJson> {
@@ -103,6 +728,11 @@ impl Default for ClientBuilder<TdJson> {
             updates_sender: None,
             tdlib_parameters: None,
             auth_state_channel_size: None,
+            parameters_fn: None,
+            extra_generator: None,
+            qr_login: false,
+            request_timeout: None,
+            updates_channel_capacity: UPDATES_BROADCAST_CAPACITY,
             tdlib_client: TdJson::new(),
         }
     }
@@ -133,6 +763,46 @@ where
         self
     }
 
+    /// Hook which is called with [tdlib parameters](crate::client::client::ClientBuilder::with_tdlib_parameters) right before they're sent to TDLib during authorization.
+    /// Allows to compute parameters, e.g. database directory, dynamically.
+    pub fn with_parameters_fn<F>(mut self, parameters_fn: F) -> Self
+    where
+        F: Fn(&mut TdlibParameters) + Send + Sync + 'static,
+    {
+        self.parameters_fn = Some(ParametersFn(Arc::new(parameters_fn)));
+        self
+    }
+
+    /// Generator of `@extra` values for requests sent by the client, e.g. `SequentialExtraGenerator` (`testing` feature) to make requests reproducible in tests.
+    /// Generated values must not collide with `@extra` of requests of other clients waiting for responses, such requests fail with [BadRequest](crate::errors::RTDError::BadRequest).
+    /// By default `@extra` set by request builder is used, which is a UUID v4.
+    pub fn with_extra_generator<G: ExtraGenerator + 'static>(mut self, extra_generator: G) -> Self {
+        self.extra_generator = Some(ExtraGeneratorFn(Arc::new(extra_generator)));
+        self
+    }
+
+    /// If enabled, client logs in by QR code instead of phone number: link to encode in QR code is passed to [handle_other_device_confirmation](crate::client::auth_handler::AuthStateHandler::handle_other_device_confirmation).
+    /// TDLib rotates the link periodically, handler is called with each new link until it's scanned by another logged in device.
+    pub fn with_qr_login(mut self, qr_login: bool) -> Self {
+        self.qr_login = qr_login;
+        self
+    }
+
+    /// Specifies how long each request waits for the response, [RTDError::RequestTimeout](crate::errors::RTDError::RequestTimeout) returned if TDLib didn't respond in time.
+    /// By default requests wait for the response indefinitely.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    /// Specifies number of updates kept for lagging [broadcast](crate::client::client::Client::subscribe_broadcast) receivers, 1000 by default.
+    /// Streams like [updates_of](crate::client::client::Client::updates_of) are backed by the same channel. Larger capacity lets consumers survive bursts of updates without losing them,
+    /// but the channel keeps that many updates in memory for the slowest receiver. Capacity of [updates sender](crate::client::client::ClientBuilder::with_updates_sender) is chosen when you create it.
+    pub fn with_updates_channel_capacity(mut self, updates_channel_capacity: usize) -> Self {
+        self.updates_channel_capacity = updates_channel_capacity;
+        self
+    }
+
     #[doc(hidden)]
     pub fn with_tdlib_client<T: TdLibClient + Clone>(self, tdlib_client: T) -> ClientBuilder<T> {
         ClientBuilder {
@@ -140,6 +810,11 @@ where
             updates_sender: self.updates_sender,
             tdlib_parameters: self.tdlib_parameters,
             auth_state_channel_size: self.auth_state_channel_size,
+            parameters_fn: self.parameters_fn,
+            extra_generator: self.extra_generator,
+            qr_login: self.qr_login,
+            request_timeout: self.request_timeout,
+            updates_channel_capacity: self.updates_channel_capacity,
         }
     }
 
@@ -148,12 +823,21 @@ where
             return Err(RTDError::BadRequest("tdlib_parameters not set"));
         };
 
-        let client = Client::new(
+        let mut client = Client::new(
             self.tdlib_client,
             self.updates_sender,
             self.tdlib_parameters.unwrap(),
             self.auth_state_channel_size,
         );
+        client.parameters_fn = self.parameters_fn;
+        client
+            .tdlib_client
+            .set_extra_generator(self.extra_generator);
+        client.qr_login = self.qr_login;
+        client
+            .tdlib_client
+            .set_request_timeout(self.request_timeout);
+        client.updates_broadcast = broadcast::channel(self.updates_channel_capacity).0;
         Ok(client)
     }
 }
@@ -177,12 +861,20 @@ where
         auth_state_channel_size: Option<usize>,
     ) -> Self {
         Self {
-            tdlib_client,
+            tdlib_client: RequestSender::new(tdlib_client),
             updates_sender,
             tdlib_parameters,
             auth_state_channel_size,
             is_started: false,
-            client_id: None,
+            client_id: Arc::new(std::sync::RwLock::new(None)),
+            self_context: Arc::new(Mutex::new(None)),
+            cache: Arc::new(ClientCache::default()),
+            updates_broadcast: broadcast::channel(UPDATES_BROADCAST_CAPACITY).0,
+            downloads_semaphore: None,
+            parameters_fn: None,
+            qr_login: false,
+            update_subscribers: Arc::new(std::sync::Mutex::new(Vec::new())),
+            dropped_updates: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -206,13 +898,1424 @@ where
         self.close(Close::builder().build()).await
     }
 
+    /// Performs the usual post-authorization bootstrap: loads current user and commonly used options.
+    /// Requests are sent one by one and only once per client, subsequent calls return cached [SelfContext](crate::client::client::SelfContext).
+    pub async fn warm_up(&self) -> RTDResult<SelfContext> {
+        let mut guard = self.self_context.lock().await;
+        if let Some(ctx) = guard.as_ref() {
+            return Ok(ctx.clone());
+        }
+        let user = self.get_me(GetMe::builder().build()).await?;
+        let mut options = HashMap::new();
+        for name in WARM_UP_OPTIONS {
+            let value = self
+                .get_option(GetOption::builder().name(name).build())
+                .await?;
+            options.insert(name.to_string(), value);
+        }
+        let ctx = SelfContext {
+            my_id: user.id(),
+            user,
+            options,
+        };
+        *guard = Some(ctx.clone());
+        Ok(ctx)
+    }
+
+    /// Returns number of unread messages in the chat, maintained from [UpdateNewChat](crate::types::UpdateNewChat) and [UpdateChatReadInbox](crate::types::UpdateChatReadInbox).
+    /// Returns 0 if no updates for the chat received yet.
+    pub fn unread_count(&self, chat_id: i64) -> i32 {
+        self.cache.unread_count(chat_id).unwrap_or_default()
+    }
+
+    /// Returns stream of `(chat_id, unread_count)` pairs, yielded each time unread count of any chat changes.
+    pub fn unread_count_changes(&self) -> impl Stream<Item = (i64, i32)> {
+        broadcast_stream(self.cache.subscribe_unread_counts())
+    }
+
+    /// Toggles whether sender signature is added to sent messages in a channel.
+    pub async fn toggle_sign_messages(&self, chat_id: i64, sign_messages: bool) -> RTDResult<()> {
+        let supergroup_id = self.supergroup_id(chat_id).await?;
+        self.toggle_supergroup_sign_messages(
+            ToggleSupergroupSignMessages::builder()
+                .supergroup_id(supergroup_id)
+                .sign_messages(sign_messages)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Toggles whether the message history of a supergroup is available to new members.
+    pub async fn toggle_all_history_available(
+        &self,
+        chat_id: i64,
+        is_all_history_available: bool,
+    ) -> RTDResult<()> {
+        let supergroup_id = self.supergroup_id(chat_id).await?;
+        self.toggle_supergroup_is_all_history_available(
+            ToggleSupergroupIsAllHistoryAvailable::builder()
+                .supergroup_id(supergroup_id)
+                .is_all_history_available(is_all_history_available)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Toggles whether messages in the chat are sent silently by default.
+    pub async fn toggle_silent_messages(
+        &self,
+        chat_id: i64,
+        default_disable_notification: bool,
+    ) -> RTDResult<()> {
+        self.toggle_chat_default_disable_notification(
+            ToggleChatDefaultDisableNotification::builder()
+                .chat_id(chat_id)
+                .default_disable_notification(default_disable_notification)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Searches for messages with the specified filter in the chat, starting from the newest one.
+    /// Pages through results internally and returns at most `limit` messages; fewer are returned if the chat has no more matches.
+    pub async fn search_chat_messages_by_filter<F: AsRef<SearchMessagesFilter>>(
+        &self,
+        chat_id: i64,
+        query: &str,
+        filter: F,
+        limit: i32,
+    ) -> RTDResult<Vec<Message>> {
+        let mut result = Vec::new();
+        let mut from_message_id = 0;
+        while (result.len() as i32) < limit {
+            let page = self
+                .search_chat_messages(
+                    SearchChatMessages::builder()
+                        .chat_id(chat_id)
+                        .query(query)
+                        .filter(filter.as_ref())
+                        .from_message_id(from_message_id)
+                        .limit((limit - result.len() as i32).min(MESSAGES_PAGE_SIZE_MAX))
+                        .build(),
+                )
+                .await?;
+            let messages: Vec<Message> = page.messages().iter().flatten().cloned().collect();
+            match messages.last() {
+                None => break,
+                Some(last) => from_message_id = last.id(),
+            }
+            result.extend(messages);
+        }
+        Ok(result)
+    }
+
+    /// Returns number of messages with the specified filter in the chat.
+    /// If `return_local` is true, count is calculated from the local database only, and -1 is returned when it can't be calculated quickly.
+    pub async fn message_count<F: AsRef<SearchMessagesFilter>>(
+        &self,
+        chat_id: i64,
+        filter: F,
+        return_local: bool,
+    ) -> RTDResult<i32> {
+        let count = self
+            .get_chat_message_count(
+                GetChatMessageCount::builder()
+                    .chat_id(chat_id)
+                    .filter(filter.as_ref())
+                    .return_local(return_local)
+                    .build(),
+            )
+            .await?;
+        Ok(count.count())
+    }
+
+    /// Sends text message in reply to the specified message of the chat.
+    pub async fn reply_to<T: AsRef<str>>(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        text: T,
+    ) -> RTDResult<Message> {
+        self.send_message(
+            SendMessage::builder()
+                .chat_id(chat_id)
+                .reply_to_message_id(message_id)
+                .input_message_content(input_message_text(
+                    FormattedText::builder().text(text).build(),
+                ))
+                .build(),
+        )
+        .await
+    }
+
+    /// Deletes files according to specified options and returns new storage usage statistics.
+    pub async fn optimize_storage_with_options(
+        &self,
+        options: &StorageOptimizeOptions,
+    ) -> RTDResult<StorageStatistics> {
+        self.optimize_storage(options.to_request()).await
+    }
+
+    /// Returns storage usage statistics with separate entries for at most `chat_limit` chats with the largest storage usage.
+    pub async fn storage_statistics(&self, chat_limit: i32) -> RTDResult<StorageStatistics> {
+        self.get_storage_statistics(
+            GetStorageStatistics::builder()
+                .chat_limit(chat_limit)
+                .build(),
+        )
+        .await
+    }
+
+    /// Joins a chat by its invite link.
+    /// Known failure reasons are returned as [JoinResult](crate::client::client::JoinResult) variants, other errors are returned as is.
+    pub async fn join_by_invite_link<T: AsRef<str>>(
+        &self,
+        invite_link: T,
+    ) -> RTDResult<JoinResult> {
+        match self
+            .join_chat_by_invite_link(
+                JoinChatByInviteLink::builder()
+                    .invite_link(invite_link)
+                    .build(),
+            )
+            .await
+        {
+            Ok(chat) => Ok(JoinResult::Joined(chat)),
+            Err(RTDError::TDLibError(err)) => match JoinResult::from_error(&err) {
+                Some(result) => Ok(result),
+                None => Err(RTDError::TDLibError(err)),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Deletes a supergroup or channel along with all messages. Basic groups can't be deleted.
+    /// Returns an error if current user is not the owner of the chat.
+    pub async fn delete_chat(&self, chat_id: i64) -> RTDResult<()> {
+        let supergroup_id = self.supergroup_id(chat_id).await?;
+        match self.my_member_status(chat_id).await? {
+            ChatMemberStatus::Creator(_) => {}
+            _ => return Err(RTDError::BadRequest("only chat owner can delete the chat")),
+        };
+        self.delete_supergroup(
+            DeleteSupergroup::builder()
+                .supergroup_id(supergroup_id)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Changes user answer to a poll. Pass empty `option_ids` to retract the vote.
+    /// Returns an error if several options chosen in a quiz or in a poll which doesn't allow multiple answers.
+    pub async fn vote_poll(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        option_ids: Vec<i32>,
+    ) -> RTDResult<()> {
+        if option_ids.len() > 1 {
+            let message = self
+                .get_message(
+                    GetMessage::builder()
+                        .chat_id(chat_id)
+                        .message_id(message_id)
+                        .build(),
+                )
+                .await?;
+            let poll = match message.content() {
+                MessageContent::MessagePoll(content) => content.poll(),
+                _ => return Err(RTDError::BadRequest("message is not a poll")),
+            };
+            match poll.type_() {
+                PollType::Regular(regular) if regular.allow_multiple_answers() => {}
+                _ => return Err(RTDError::BadRequest("poll does not allow multiple answers")),
+            };
+        };
+        self.set_poll_answer(
+            SetPollAnswer::builder()
+                .chat_id(chat_id)
+                .message_id(message_id)
+                .option_ids(option_ids)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Stops a poll. A poll in a message can be stopped when the message has can_be_edited flag set.
+    pub async fn close_poll(&self, chat_id: i64, message_id: i64) -> RTDResult<()> {
+        self.stop_poll(
+            StopPoll::builder()
+                .chat_id(chat_id)
+                .message_id(message_id)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Resends messages which failed to send, e.g. after [UpdateMessageSendFailed](crate::types::UpdateMessageSendFailed) received.
+    /// Returns new messages in the same order as `message_ids`; messages which can't be resent are skipped.
+    pub async fn resend_failed_messages(
+        &self,
+        chat_id: i64,
+        message_ids: Vec<i64>,
+    ) -> RTDResult<Vec<Message>> {
+        let messages = self
+            .resend_messages(
+                ResendMessages::builder()
+                    .chat_id(chat_id)
+                    .message_ids(message_ids)
+                    .build(),
+            )
+            .await?;
+        Ok(messages.messages().iter().flatten().cloned().collect())
+    }
+
+    /// Returns all updates needed to restore current TDLib state, see [get_current_state](crate::client::client::Client::get_current_state).
+    /// Returned updates are also applied to client-side caches, e.g. [unread_count](crate::client::client::Client::unread_count), so they are actual after restart.
+    pub async fn current_state(&self) -> RTDResult<Vec<Update>> {
+        let state = self
+            .get_current_state(GetCurrentState::builder().build())
+            .await?;
+        for update in state.updates() {
+            self.cache.handle_update(update);
+        }
+        Ok(state.updates().clone())
+    }
+
+    /// Sends an inline query to a bot and returns its results.
+    /// Use [send_inline_result](crate::client::client::Client::send_inline_result) to send chosen result to the chat.
+    pub async fn query_inline_bot<T: AsRef<str>>(
+        &self,
+        bot_user_id: i32,
+        chat_id: i64,
+        query: T,
+    ) -> RTDResult<InlineQueryResults> {
+        self.get_inline_query_results(
+            GetInlineQueryResults::builder()
+                .bot_user_id(bot_user_id)
+                .chat_id(chat_id)
+                .query(query)
+                .build(),
+        )
+        .await
+    }
+
+    /// Sends the result of an inline query, received with [query_inline_bot](crate::client::client::Client::query_inline_bot), as a message.
+    pub async fn send_inline_result<T: AsRef<str>>(
+        &self,
+        chat_id: i64,
+        query_id: i64,
+        result_id: T,
+    ) -> RTDResult<Message> {
+        self.send_inline_query_result_message(
+            SendInlineQueryResultMessage::builder()
+                .chat_id(chat_id)
+                .query_id(query_id)
+                .result_id(result_id)
+                .build(),
+        )
+        .await
+    }
+
+    /// Returns notification settings of the chat.
+    /// Settings with `use_default_*` flag set are inherited from the scope, see [get_default_notification_settings](crate::client::client::Client::get_default_notification_settings).
+    pub async fn get_notification_settings(
+        &self,
+        chat_id: i64,
+    ) -> RTDResult<ChatNotificationSettings> {
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        Ok(chat.notification_settings().clone())
+    }
+
+    /// Returns notification settings of the scope the chat belongs to: private chats, groups or channels.
+    pub async fn get_default_notification_settings(
+        &self,
+        chat_id: i64,
+    ) -> RTDResult<ScopeNotificationSettings> {
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        let scope = match chat.type_() {
+            ChatType::Private(_) | ChatType::Secret(_) => NotificationSettingsScope::PrivateChats(
+                NotificationSettingsScopePrivateChats::builder().build(),
+            ),
+            ChatType::Supergroup(supergroup) if supergroup.is_channel() => {
+                NotificationSettingsScope::ChannelChats(
+                    NotificationSettingsScopeChannelChats::builder().build(),
+                )
+            }
+            _ => NotificationSettingsScope::GroupChats(
+                NotificationSettingsScopeGroupChats::builder().build(),
+            ),
+        };
+        self.get_scope_notification_settings(
+            GetScopeNotificationSettings::builder().scope(scope).build(),
+        )
+        .await
+    }
+
+    /// Downloads the file and waits until download completes.
+    /// Number of simultaneous downloads may be limited with [with_max_concurrent_downloads](crate::client::worker::WorkerBuilder::with_max_concurrent_downloads).
+    pub async fn download_file_to_completion(&self, file_id: i32) -> RTDResult<File> {
+        let _permit = match &self.downloads_semaphore {
+            None => None,
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire()
+                    .await
+                    .map_err(|_| RTDError::Internal("downloads semaphore closed"))?,
+            ),
+        };
+        self.download_file(
+            DownloadFile::builder()
+                .file_id(file_id)
+                .priority(1)
+                .synchronous(true)
+                .build(),
+        )
+        .await
+    }
+
+    /// Starts downloading the file and returns stream of its progress, built from [UpdateFile](crate::types::UpdateFile) updates of the file.
+    /// `priority` is from 1 to 32, the higher the value, the earlier the file is downloaded.
+    /// Stream ends after the item with [completed](crate::client::client::DownloadProgress::is_completed) download, or after an error, e.g. if download was cancelled.
+    pub fn download_file_stream(
+        &self,
+        file_id: i32,
+        priority: i32,
+    ) -> impl Stream<Item = RTDResult<DownloadProgress>> {
+        // subscribe before the request, so updates sent meanwhile aren't lost
+        let updates =
+            broadcast_stream(self.subscribe_broadcast()).filter_map(move |update| async move {
+                match *update {
+                    Update::File(update) if update.file().id() == file_id => {
+                        Some(Ok(update.file().clone()))
+                    }
+                    _ => None,
+                }
+            });
+        let client = self.clone();
+        let started = futures::stream::once(async move {
+            client
+                .download_file(
+                    DownloadFile::builder()
+                        .file_id(file_id)
+                        .priority(priority)
+                        .build(),
+                )
+                .await
+        });
+        // stream is dropped after the last item, so it ends without waiting for more updates
+        let files = Box::pin(started.chain(updates));
+        futures::stream::unfold(Some(files), |files| async move {
+            let mut files = files?;
+            let progress = files.next().await?.and_then(|file| {
+                let progress = DownloadProgress { file };
+                if !progress.is_completed() && !progress.file().local().is_downloading_active() {
+                    return Err(RTDError::Internal("file download stopped"));
+                }
+                Ok(progress)
+            });
+            let finished = match &progress {
+                Ok(progress) => progress.is_completed(),
+                Err(_) => true,
+            };
+            Some((progress, if finished { None } else { Some(files) }))
+        })
+    }
+
+    /// Returns the chat like [get_chat](crate::client::client::Client::get_chat), but if its last message isn't known yet, e.g. right after the chat was created,
+    /// waits briefly for [UpdateChatLastMessage](crate::types::UpdateChatLastMessage) and returns the chat with populated fields. Chat without messages is returned as is.
+    pub async fn get_chat_loaded(&self, chat_id: i64) -> RTDResult<Chat> {
+        // subscribe before the request, so the update isn't missed
+        let last_message_updates = broadcast_stream(self.subscribe_broadcast()).filter(|update| {
+            futures::future::ready(matches!(
+                update.as_ref(),
+                Update::ChatLastMessage(update) if update.chat_id() == chat_id
+            ))
+        });
+        futures::pin_mut!(last_message_updates);
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        if chat.last_message().is_some() {
+            return Ok(chat);
+        }
+        if tokio::time::timeout(CHAT_LOAD_TIMEOUT, last_message_updates.next())
+            .await
+            .is_err()
+        {
+            return Ok(chat);
+        }
+        self.get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await
+    }
+
+    /// Returns information about a supergroup or a channel, without requesting [full info](crate::types::SupergroupFullInfo).
+    pub async fn supergroup(&self, supergroup_id: i32) -> RTDResult<Supergroup> {
+        self.get_supergroup(
+            GetSupergroup::builder()
+                .supergroup_id(supergroup_id)
+                .build(),
+        )
+        .await
+    }
+
+    /// Returns information about a basic group, without requesting [full info](crate::types::BasicGroupFullInfo).
+    pub async fn basic_group(&self, basic_group_id: i32) -> RTDResult<BasicGroup> {
+        self.get_basic_group(
+            GetBasicGroup::builder()
+                .basic_group_id(basic_group_id)
+                .build(),
+        )
+        .await
+    }
+
+    /// Returns number of members in a basic group, supergroup or channel.
+    /// The value is requested from [full info](crate::types::SupergroupFullInfo) once and then kept up to date from received updates.
+    pub async fn member_count(&self, chat_id: i64) -> RTDResult<i32> {
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        match chat.type_() {
+            ChatType::Supergroup(supergroup) => {
+                let supergroup_id = supergroup.supergroup_id();
+                if let Some(member_count) = self.cache.supergroup_member_count(supergroup_id) {
+                    return Ok(member_count);
+                }
+                let full_info = self
+                    .get_supergroup_full_info(
+                        GetSupergroupFullInfo::builder()
+                            .supergroup_id(supergroup_id)
+                            .build(),
+                    )
+                    .await?;
+                self.cache
+                    .set_supergroup_member_count(supergroup_id, full_info.member_count());
+                Ok(full_info.member_count())
+            }
+            ChatType::BasicGroup(basic_group) => {
+                let basic_group_id = basic_group.basic_group_id();
+                if let Some(member_count) = self.cache.basic_group_member_count(basic_group_id) {
+                    return Ok(member_count);
+                }
+                let full_info = self
+                    .get_basic_group_full_info(
+                        GetBasicGroupFullInfo::builder()
+                            .basic_group_id(basic_group_id)
+                            .build(),
+                    )
+                    .await?;
+                let member_count = full_info.members().len() as i32;
+                self.cache
+                    .set_basic_group_member_count(basic_group_id, member_count);
+                Ok(member_count)
+            }
+            _ => Err(RTDError::BadRequest("chat is not a group or channel")),
+        }
+    }
+
+    /// Changes slow mode delay of the chat. Allowed values are 0 (slow mode disabled), 10, 30, 60, 300, 900 and 3600 seconds,
+    /// [RTDError::InvalidParameters](crate::errors::RTDError::InvalidParameters) returned for any other value.
+    pub async fn set_slow_mode(&self, chat_id: i64, seconds: i32) -> RTDResult<()> {
+        if !SLOW_MODE_DELAYS.contains(&seconds) {
+            return Err(RTDError::InvalidParameters(
+                "slow mode delay must be one of 0, 10, 30, 60, 300, 900, 3600",
+            ));
+        }
+        self.set_chat_slow_mode_delay(
+            SetChatSlowModeDelay::builder()
+                .chat_id(chat_id)
+                .slow_mode_delay(seconds)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Returns stream of `(chat_id, user_id, action)` tuples from [UpdateUserChatAction](crate::types::UpdateUserChatAction), e.g. when a user starts typing.
+    pub fn chat_actions_stream(&self) -> impl Stream<Item = (i64, i32, ChatAction)> {
+        broadcast_stream(self.subscribe_broadcast()).filter_map(|update| async move {
+            match *update {
+                Update::UserChatAction(action) => {
+                    Some((action.chat_id(), action.user_id(), action.action().clone()))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns stream of notification updates: [UpdateActiveNotifications](crate::types::UpdateActiveNotifications), [UpdateNotificationGroup](crate::types::UpdateNotificationGroup),
+    /// [UpdateNotification](crate::types::UpdateNotification) and [UpdateHavePendingNotifications](crate::types::UpdateHavePendingNotifications).
+    /// Together they describe notifications to be shown in the system tray, see [dismiss_notification_group](crate::client::client::Client::dismiss_notification_group).
+    pub fn notifications_stream(&self) -> impl Stream<Item = Update> {
+        broadcast_stream(self.subscribe_broadcast()).filter_map(|update| async move {
+            match *update {
+                update @ Update::ActiveNotifications(_)
+                | update @ Update::NotificationGroup(_)
+                | update @ Update::Notification(_)
+                | update @ Update::HavePendingNotifications(_) => Some(update),
+                _ => None,
+            }
+        })
+    }
+
+    /// Removes the active notification from the notification list, it needs to be hidden from the system tray.
+    pub async fn dismiss_notification(
+        &self,
+        notification_group_id: i32,
+        notification_id: i32,
+    ) -> RTDResult<()> {
+        self.remove_notification(
+            RemoveNotification::builder()
+                .notification_group_id(notification_group_id)
+                .notification_id(notification_id)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Removes notifications of the group with identifiers up to `max_notification_id`, they need to be hidden from the system tray.
+    pub async fn dismiss_notification_group(
+        &self,
+        notification_group_id: i32,
+        max_notification_id: i32,
+    ) -> RTDResult<()> {
+        self.remove_notification_group(
+            RemoveNotificationGroup::builder()
+                .notification_group_id(notification_group_id)
+                .max_notification_id(max_notification_id)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Returns stream of updates of a single type, e.g. `client.updates_of::<UpdateNewMessage>()`.
+    /// Stream is backed by [subscribe_broadcast](crate::client::client::Client::subscribe_broadcast), so a slow consumer loses oldest updates.
+    pub fn updates_of<U: UpdateVariant>(&self) -> impl Stream<Item = U> {
+        broadcast_stream(self.subscribe_broadcast())
+            .filter_map(|update| async move { U::from_update(*update) })
+    }
+
+    /// Transfers ownership of the chat to another user. Requires two-step verification password of the current user.
+    pub async fn transfer_ownership<T: AsRef<str>>(
+        &self,
+        chat_id: i64,
+        new_owner_user_id: i32,
+        password: T,
+    ) -> RTDResult<(), TransferOwnershipError> {
+        self.transfer_chat_ownership(
+            TransferChatOwnership::builder()
+                .chat_id(chat_id)
+                .user_id(new_owner_user_id)
+                .password(password)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Sends text message and waits until it's actually sent.
+    /// Returned [SentMessage](crate::client::client::SentMessage) allows to edit, delete or pin the message later.
+    /// Text longer than `message_text_length_max` option is rejected with [RTDError::InvalidParameters](crate::errors::RTDError::InvalidParameters),
+    /// use [send_long_text_message](crate::client::client::Client::send_long_text_message) to split it instead.
+    pub async fn send_text_message<T: AsRef<str>>(
+        &self,
+        chat_id: i64,
+        text: T,
+    ) -> RTDResult<SentMessage<R>> {
+        if text.as_ref().encode_utf16().count() > self.message_text_length_max() {
+            return Err(RTDError::InvalidParameters(
+                "message text is longer than message_text_length_max",
+            ));
+        }
+        // subscribe before sending, so send result can't be missed
+        let mut updates = self.subscribe_broadcast();
+        let message = self
+            .send_message(
+                SendMessage::builder()
+                    .chat_id(chat_id)
+                    .input_message_content(input_message_text(
+                        FormattedText::builder().text(text).build(),
+                    ))
+                    .build(),
+            )
+            .await?;
+        let message = self.wait_message_sent(&mut updates, message).await?;
+        Ok(SentMessage {
+            client: self.clone(),
+            message,
+        })
+    }
+
+    /// Sends plain text message and returns the message from the response, without waiting until it's actually sent.
+    /// The returned message is the pending one, with a temporary identifier; use [send_text_message](crate::client::client::Client::send_text_message) to wait for the sent one.
+    pub async fn send_text<T: Into<String>>(&self, chat_id: i64, text: T) -> RTDResult<Message> {
+        self.send_text_formatted(chat_id, FormattedText::builder().text(text.into()).build())
+            .await
+    }
+
+    /// Sends text message with entities, e.g. built with [FormattedTextBuilder](crate::types::FormattedTextBuilder), see [send_text](crate::client::client::Client::send_text).
+    /// Text longer than `message_text_length_max` option is rejected with [RTDError::InvalidParameters](crate::errors::RTDError::InvalidParameters).
+    pub async fn send_text_formatted(
+        &self,
+        chat_id: i64,
+        text: FormattedText,
+    ) -> RTDResult<Message> {
+        if text.text().encode_utf16().count() > self.message_text_length_max() {
+            return Err(RTDError::InvalidParameters(
+                "message text is longer than message_text_length_max",
+            ));
+        }
+        self.send_message(
+            SendMessage::builder()
+                .chat_id(chat_id)
+                .input_message_content(input_message_text(text))
+                .build(),
+        )
+        .await
+    }
+
+    /// Parses text with [MarkdownV2](https://core.telegram.org/bots/api#markdownv2-style) markup into [FormattedText](crate::types::FormattedText) with entities.
+    /// Parsing is done synchronously by TDLib, without network requests.
+    pub fn parse_markdown_v2<T: AsRef<str>>(&self, text: T) -> RTDResult<FormattedText> {
+        self.parse_text(
+            text,
+            TextParseMode::Markdown(TextParseModeMarkdown::builder().version(2).build()),
+        )
+    }
+
+    /// Parses text with [HTML](https://core.telegram.org/bots/api#html-style) markup into [FormattedText](crate::types::FormattedText) with entities.
+    /// Parsing is done synchronously by TDLib, without network requests.
+    pub fn parse_html<T: AsRef<str>>(&self, text: T) -> RTDResult<FormattedText> {
+        self.parse_text(
+            text,
+            TextParseMode::HTML(TextParseModeHTML::builder().build()),
+        )
+    }
+
+    fn parse_text<T: AsRef<str>>(
+        &self,
+        text: T,
+        parse_mode: TextParseMode,
+    ) -> RTDResult<FormattedText> {
+        self.execute(
+            ParseTextEntities::builder()
+                .text(text)
+                .parse_mode(parse_mode)
+                .build(),
+        )
+    }
+
+    /// Sends text message, splitting it into several messages if it's longer than `message_text_length_max` option.
+    pub async fn send_long_text_message<T: AsRef<str>>(
+        &self,
+        chat_id: i64,
+        text: T,
+    ) -> RTDResult<Vec<SentMessage<R>>> {
+        let mut sent = Vec::new();
+        for part in split_text(text.as_ref(), self.message_text_length_max()) {
+            sent.push(self.send_text_message(chat_id, part).await?);
+        }
+        Ok(sent)
+    }
+
+    /// Returns stream of forwards of the message to public channels, requesting pages lazily.
+    /// Stream ends after the last page or the first error.
+    pub fn message_public_forwards_stream(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+    ) -> impl Stream<Item = RTDResult<Message>> {
+        let state = (self.clone(), Some(String::new()), VecDeque::new());
+        futures::stream::unfold(state, move |(client, mut offset, mut buffer)| async move {
+            loop {
+                if let Some(message) = buffer.pop_front() {
+                    return Some((Ok(message), (client, offset, buffer)));
+                }
+                let current_offset = offset?;
+                match client
+                    .get_message_public_forwards(
+                        GetMessagePublicForwards::builder()
+                            .chat_id(chat_id)
+                            .message_id(message_id)
+                            .offset(current_offset)
+                            .limit(MESSAGES_PAGE_SIZE_MAX)
+                            .build(),
+                    )
+                    .await
+                {
+                    Err(err) => return Some((Err(err), (client, None, buffer))),
+                    Ok(page) => {
+                        buffer.extend(page.messages().iter().cloned());
+                        offset = match page.next_offset().is_empty() || page.messages().is_empty() {
+                            true => None,
+                            false => Some(page.next_offset().clone()),
+                        };
+                    }
+                }
+            }
+        })
+    }
+
+    /// Creates a new voice call with the user. Media exchange is not handled by the library, so the call only passes signaling stage.
+    pub async fn call_user<P: AsRef<CallProtocol>>(
+        &self,
+        user_id: i32,
+        protocol: P,
+    ) -> RTDResult<CallId> {
+        self.create_call(
+            CreateCall::builder()
+                .user_id(user_id)
+                .protocol(protocol)
+                .build(),
+        )
+        .await
+    }
+
+    /// Accepts an incoming call.
+    pub async fn answer_call<P: AsRef<CallProtocol>>(
+        &self,
+        call_id: i32,
+        protocol: P,
+    ) -> RTDResult<()> {
+        self.accept_call(
+            AcceptCall::builder()
+                .call_id(call_id)
+                .protocol(protocol)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Declines an incoming call or hangs up an active one.
+    pub async fn hang_up_call(&self, call_id: i32) -> RTDResult<()> {
+        self.discard_call(DiscardCall::builder().call_id(call_id).build())
+            .await?;
+        Ok(())
+    }
+
+    /// Returns stream of call state changes received with [UpdateCall](crate::types::UpdateCall).
+    pub fn calls_stream(&self) -> impl Stream<Item = Call> {
+        broadcast_stream(self.subscribe_broadcast()).filter_map(|update| async move {
+            match *update {
+                Update::Call(call) => Some(call.call().clone()),
+                _ => None,
+            }
+        })
+    }
+
+    // Returns maximum text length, as reported by TDLib
+    fn message_text_length_max(&self) -> usize {
+        self.cache
+            .option_integer("message_text_length_max")
+            .unwrap_or(MESSAGE_TEXT_LENGTH_MAX_DEFAULT) as usize
+    }
+
+    /// Returns recently used stickers, or recently attached to photos and videos if `is_attached` is true.
+    pub async fn recent_stickers(&self, is_attached: bool) -> RTDResult<Vec<Sticker>> {
+        let stickers = self
+            .get_recent_stickers(
+                GetRecentStickers::builder()
+                    .is_attached(is_attached)
+                    .build(),
+            )
+            .await?;
+        Ok(stickers.stickers().clone())
+    }
+
+    /// Adds already uploaded sticker to the beginning of the recent stickers list and returns the updated list.
+    /// TDLib removes the oldest sticker if the list is full.
+    pub async fn add_recent_sticker_by_id(
+        &self,
+        file_id: i32,
+        is_attached: bool,
+    ) -> RTDResult<Vec<Sticker>> {
+        let stickers = self
+            .add_recent_sticker(
+                AddRecentSticker::builder()
+                    .is_attached(is_attached)
+                    .sticker(InputFile::Id(InputFileId::builder().id(file_id).build()))
+                    .build(),
+            )
+            .await?;
+        Ok(stickers.stickers().clone())
+    }
+
+    /// Returns favorite stickers.
+    pub async fn favorite_stickers(&self) -> RTDResult<Vec<Sticker>> {
+        let stickers = self
+            .get_favorite_stickers(GetFavoriteStickers::builder().build())
+            .await?;
+        Ok(stickers.stickers().clone())
+    }
+
+    /// Determines whether current user can send text messages to the chat, based on the chat permissions and user's member status.
+    pub async fn can_send_message(&self, chat_id: i64) -> RTDResult<bool> {
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        let is_channel = match chat.type_() {
+            ChatType::Private(_) | ChatType::Secret(_) => return Ok(true),
+            ChatType::Supergroup(supergroup) => supergroup.is_channel(),
+            _ => false,
+        };
+        let status = self.my_member_status(chat_id).await?;
+        let can_send = match status {
+            ChatMemberStatus::Creator(creator) => creator.is_member(),
+            ChatMemberStatus::Administrator(admin) => !is_channel || admin.can_post_messages(),
+            ChatMemberStatus::Member(_) => !is_channel && chat.permissions().can_send_messages(),
+            ChatMemberStatus::Restricted(restricted) => {
+                !is_channel
+                    && restricted.is_member()
+                    && restricted.permissions().can_send_messages()
+                    && chat.permissions().can_send_messages()
+            }
+            _ => false,
+        };
+        Ok(can_send)
+    }
+
+    /// Returns identifiers of up to `limit` first chats from the chat list, in which current user can send messages.
+    /// See [can_send_message](crate::client::client::Client::can_send_message).
+    pub async fn get_sendable_chats<L: AsRef<ChatList>>(
+        &self,
+        chat_list: L,
+        limit: i32,
+    ) -> RTDResult<Vec<i64>> {
+        let chats = self
+            .get_chats(
+                GetChats::builder()
+                    .chat_list(chat_list)
+                    .offset_order(i64::MAX)
+                    .limit(limit)
+                    .build(),
+            )
+            .await?;
+        let mut sendable = Vec::new();
+        for &chat_id in chats.chat_ids() {
+            if self.can_send_message(chat_id).await? {
+                sendable.push(chat_id);
+            }
+        }
+        Ok(sendable)
+    }
+
+    /// Returns identifiers of all chats from the chat list: [main](crate::types::ChatListMain), [archive](crate::types::ChatListArchive) or [filter](crate::types::ChatListFilter).
+    /// Requests chats page by page, until TDLib returns an empty page.
+    pub async fn get_all_chats<L: AsRef<ChatList>>(&self, chat_list: L) -> RTDResult<Vec<i64>> {
+        let chat_list = chat_list.as_ref();
+        let mut result = Vec::new();
+        let mut offset_order = i64::MAX;
+        let mut offset_chat_id = 0;
+        loop {
+            let page = self
+                .get_chats(
+                    GetChats::builder()
+                        .chat_list(chat_list)
+                        .offset_order(offset_order)
+                        .offset_chat_id(offset_chat_id)
+                        .limit(CHATS_PAGE_SIZE)
+                        .build(),
+                )
+                .await?;
+            let last_chat_id = match page.chat_ids().last() {
+                None => break,
+                Some(&last_chat_id) => last_chat_id,
+            };
+            result.extend(page.chat_ids());
+            // next page starts after position of the last chat in the list
+            let last_chat = self
+                .get_chat(GetChat::builder().chat_id(last_chat_id).build())
+                .await?;
+            let position = last_chat
+                .positions()
+                .iter()
+                .flatten()
+                .find(|position| is_same_chat_list(position.list(), chat_list));
+            match position {
+                Some(position) => {
+                    offset_order = position.order();
+                    offset_chat_id = last_chat_id;
+                }
+                // chat removed from the list meanwhile, so position is unknown
+                None => break,
+            }
+        }
+        Ok(result)
+    }
+
+    /// Opens the chat and returns guard which closes it when dropped.
+    /// TDLib expects opened chats to be shown to the user: it loads history and maintains unread state reliably only for them.
+    pub async fn open_chat_guarded(&self, chat_id: i64) -> RTDResult<ChatGuard<R>>
+    where
+        R: Send + Sync + 'static,
+    {
+        self.open_chat(OpenChat::builder().chat_id(chat_id).build())
+            .await?;
+        Ok(ChatGuard {
+            client: Some(self.clone()),
+            chat_id,
+        })
+    }
+
+    /// Returns up to `limit` last messages of the chat, newest first. Chat is opened while history is loaded.
+    pub async fn load_chat_history(&self, chat_id: i64, limit: i32) -> RTDResult<Vec<Message>>
+    where
+        R: Send + Sync + 'static,
+    {
+        let guard = self.open_chat_guarded(chat_id).await?;
+        let mut result = Vec::new();
+        let mut from_message_id = 0;
+        while (result.len() as i32) < limit {
+            let page = self
+                .get_chat_history(
+                    GetChatHistory::builder()
+                        .chat_id(chat_id)
+                        .from_message_id(from_message_id)
+                        .limit((limit - result.len() as i32).min(MESSAGES_PAGE_SIZE_MAX))
+                        .build(),
+                )
+                .await?;
+            let messages: Vec<Message> = page.messages().iter().flatten().cloned().collect();
+            match messages.last() {
+                None => break,
+                Some(last) => from_message_id = last.id(),
+            }
+            result.extend(messages);
+        }
+        guard.close().await?;
+        Ok(result)
+    }
+
+    /// Returns stream of messages with calls, newest first, requesting pages lazily.
+    /// If `only_missed` is true, only missed and declined calls are returned. Stream ends after the last page or the first error.
+    pub fn call_history_stream(&self, only_missed: bool) -> impl Stream<Item = RTDResult<Message>> {
+        let state = (self.clone(), Some(0), VecDeque::new());
+        futures::stream::unfold(
+            state,
+            move |(client, mut from_message_id, mut buffer)| async move {
+                loop {
+                    if let Some(message) = buffer.pop_front() {
+                        return Some((Ok(message), (client, from_message_id, buffer)));
+                    }
+                    let current_from = from_message_id?;
+                    match client
+                        .search_call_messages(
+                            SearchCallMessages::builder()
+                                .from_message_id(current_from)
+                                .limit(MESSAGES_PAGE_SIZE_MAX)
+                                .only_missed(only_missed)
+                                .build(),
+                        )
+                        .await
+                    {
+                        Err(err) => return Some((Err(err), (client, None, buffer))),
+                        Ok(page) => {
+                            buffer.extend(page.messages().iter().flatten().cloned());
+                            from_message_id = buffer.back().map(|last: &Message| last.id());
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Returns stream of `(name, value)` pairs received with [UpdateOption](crate::types::UpdateOption) each time an option changes.
+    pub fn option_changes_stream(&self) -> impl Stream<Item = (String, OptionValue)> {
+        broadcast_stream(self.subscribe_broadcast()).filter_map(|update| async move {
+            match *update {
+                Update::Option(option) => Some((option.name().clone(), option.value().clone())),
+                _ => None,
+            }
+        })
+    }
+
+    /// Links discussion group to the channel, so channel posts can be commented there. Pass `None` to unlink current discussion group.
+    pub async fn set_discussion_group(
+        &self,
+        channel_chat_id: i64,
+        group_chat_id: Option<i64>,
+    ) -> RTDResult<()> {
+        self.set_chat_discussion_group(
+            SetChatDiscussionGroup::builder()
+                .chat_id(channel_chat_id)
+                .discussion_chat_id(group_chat_id.unwrap_or(0))
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Sets the list of commands supported by the bot, from `(command, description)` pairs. Replaces previously set list.
+    pub async fn set_bot_commands<I, C, D>(&self, commands: I) -> RTDResult<()>
+    where
+        I: IntoIterator<Item = (C, D)>,
+        C: AsRef<str>,
+        D: AsRef<str>,
+    {
+        let commands = commands
+            .into_iter()
+            .map(|(command, description)| {
+                BotCommand::builder()
+                    .command(command)
+                    .description(description)
+                    .build()
+            })
+            .collect();
+        self.set_commands(SetCommands::builder().commands(commands).build())
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the list of commands supported by the bot. Returns empty list if the user is not a bot.
+    pub async fn bot_commands(&self, bot_user_id: i32) -> RTDResult<Vec<BotCommand>> {
+        let full_info = self
+            .get_user_full_info(GetUserFullInfo::builder().user_id(bot_user_id).build())
+            .await?;
+        Ok(match full_info.bot_info() {
+            None => Vec::new(),
+            Some(bot_info) => bot_info.commands().clone(),
+        })
+    }
+
+    /// Deletes the account of the current user with all its data. **This action can't be undone.**
+    /// `confirmed` must be true, otherwise [RTDError::InvalidParameters](crate::errors::RTDError::InvalidParameters) returned and nothing is sent to TDLib.
+    pub async fn irreversibly_delete_account<T: AsRef<str>>(
+        &self,
+        reason: T,
+        confirmed: bool,
+    ) -> RTDResult<()> {
+        if !confirmed {
+            return Err(RTDError::InvalidParameters(
+                "account deletion must be explicitly confirmed",
+            ));
+        }
+        log::warn!("deleting account");
+        self.delete_account(DeleteAccount::builder().reason(reason).build())
+            .await?;
+        Ok(())
+    }
+
+    /// Returns information about a [login url button](crate::types::InlineKeyboardButtonTypeLoginUrl) of the message, first step of the login flow.
+    /// If [LoginUrlInfo::Open](crate::types::LoginUrlInfo::Open) returned, the url needs to be opened as is; otherwise user must be asked for confirmation
+    /// and the url retrieved with [login_url](crate::client::client::Client::login_url).
+    pub async fn login_url_info(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        button_id: i32,
+    ) -> RTDResult<LoginUrlInfo> {
+        self.get_login_url_info(
+            GetLoginUrlInfo::builder()
+                .chat_id(chat_id)
+                .message_id(message_id)
+                .button_id(button_id)
+                .build(),
+        )
+        .await
+    }
+
+    /// Confirms login via [login url button](crate::types::InlineKeyboardButtonTypeLoginUrl) and returns url to be opened, second step of the login flow.
+    /// Must be called only after user confirmed [LoginUrlInfo::RequestConfirmation](crate::types::LoginUrlInfo::RequestConfirmation);
+    /// `allow_write_access` must be false unless the bot requested it and user agreed.
+    pub async fn login_url(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        button_id: i32,
+        allow_write_access: bool,
+    ) -> RTDResult<String> {
+        let url = self
+            .get_login_url(
+                GetLoginUrl::builder()
+                    .chat_id(chat_id)
+                    .message_id(message_id)
+                    .button_id(button_id)
+                    .allow_write_access(allow_write_access)
+                    .build(),
+            )
+            .await?;
+        Ok(url.url().clone())
+    }
+
+    /// Marks the chat as unread or removes the mark. The mark doesn't affect [unread count](crate::types::Chat::unread_count) of the chat.
+    pub async fn mark_unread(&self, chat_id: i64, is_marked_as_unread: bool) -> RTDResult<()> {
+        self.toggle_chat_is_marked_as_unread(
+            ToggleChatIsMarkedAsUnread::builder()
+                .chat_id(chat_id)
+                .is_marked_as_unread(is_marked_as_unread)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Moves the chat to the Archive chat list.
+    pub async fn archive_chat(&self, chat_id: i64) -> RTDResult<()> {
+        self.move_chat_to_list(
+            chat_id,
+            ChatList::Archive(ChatListArchive::builder().build()),
+        )
+        .await
+    }
+
+    /// Moves the chat from the Archive back to the Main chat list.
+    pub async fn unarchive_chat(&self, chat_id: i64) -> RTDResult<()> {
+        self.move_chat_to_list(chat_id, ChatList::Main(ChatListMain::builder().build()))
+            .await
+    }
+
+    /// Clears the list of recently used stickers, or of stickers recently attached to photo or video files if `is_attached` is true.
+    pub async fn reset_recent_stickers(&self, is_attached: bool) -> RTDResult<()> {
+        self.clear_recent_stickers(
+            ClearRecentStickers::builder()
+                .is_attached(is_attached)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Clears the list of recently found chats, see [remove_recently_found_chat](crate::client::client::Client::remove_recently_found_chat) to remove a single chat.
+    pub async fn clear_recent_chats(&self) -> RTDResult<()> {
+        self.clear_recently_found_chats(ClearRecentlyFoundChats::builder().build())
+            .await?;
+        Ok(())
+    }
+
+    /// Searches for emojis by keywords. If `exact_match` is true, only emojis whose keywords exactly match `query` are returned.
+    /// `input_language_codes` are IETF language tags of the user's input languages, keywords of the current interface language are always used.
+    pub async fn find_emojis<Q, I, L>(
+        &self,
+        query: Q,
+        exact_match: bool,
+        input_language_codes: I,
+    ) -> RTDResult<Vec<String>>
+    where
+        Q: AsRef<str>,
+        I: IntoIterator<Item = L>,
+        L: AsRef<str>,
+    {
+        let emojis = self
+            .search_emojis(
+                SearchEmojis::builder()
+                    .text(query)
+                    .exact_match(exact_match)
+                    .input_language_codes(
+                        input_language_codes
+                            .into_iter()
+                            .map(|code| code.as_ref().to_string())
+                            .collect(),
+                    )
+                    .build(),
+            )
+            .await?;
+        Ok(emojis.emojis().clone())
+    }
+
+    /// Returns sticker sets attached to the file. Currently only photos and videos can have attached sticker sets.
+    pub async fn attached_sticker_sets(&self, file_id: i32) -> RTDResult<Vec<StickerSetInfo>> {
+        let sticker_sets = self
+            .get_attached_sticker_sets(GetAttachedStickerSets::builder().file_id(file_id).build())
+            .await?;
+        Ok(sticker_sets.sets().clone())
+    }
+
+    /// Updates the game score of the user in the game message sent by the bot; the message is edited to show the new scores.
+    /// If `force` is false, the score is updated only if it's higher than the current one. Returns the edited message. For bots only.
+    pub async fn update_game_score(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        user_id: i32,
+        score: i32,
+        force: bool,
+    ) -> RTDResult<Message> {
+        self.set_game_score(
+            SetGameScore::builder()
+                .chat_id(chat_id)
+                .message_id(message_id)
+                .edit_message(true)
+                .user_id(user_id)
+                .score(score)
+                .force(force)
+                .build(),
+        )
+        .await
+    }
+
+    /// Returns high scores of the game in the message sent by the bot, along with scores of players close to the user. For bots only.
+    pub async fn game_high_scores(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        user_id: i32,
+    ) -> RTDResult<Vec<GameHighScore>> {
+        let scores = self
+            .get_game_high_scores(
+                GetGameHighScores::builder()
+                    .chat_id(chat_id)
+                    .message_id(message_id)
+                    .user_id(user_id)
+                    .build(),
+            )
+            .await?;
+        Ok(scores.scores().clone())
+    }
+
+    /// Returns the current state of 2-step verification, see [change_password](crate::client::client::Client::change_password).
+    pub async fn password_state(&self) -> RTDResult<PasswordState> {
+        self.get_password_state(GetPasswordState::builder().build())
+            .await
+    }
+
+    /// Changes the password for 2-step verification; empty `new_password` removes it.
+    /// If `recovery_email` is specified, the recovery email address is changed too; it must be confirmed with the code sent to it,
+    /// check [recovery_email_address_code_info](crate::types::PasswordState::recovery_email_address_code_info) of the returned state.
+    pub async fn change_password<O, N, H>(
+        &self,
+        old_password: O,
+        new_password: N,
+        new_hint: H,
+        recovery_email: Option<&str>,
+    ) -> RTDResult<PasswordState>
+    where
+        O: AsRef<str>,
+        N: AsRef<str>,
+        H: AsRef<str>,
+    {
+        self.set_password(
+            SetPassword::builder()
+                .old_password(old_password)
+                .new_password(new_password)
+                .new_hint(new_hint)
+                .set_recovery_email_address(recovery_email.is_some())
+                .new_recovery_email_address(recovery_email.unwrap_or_default())
+                .build(),
+        )
+        .await
+    }
+
+    /// Deletes all messages sent by `sender` in the chat. Requires can_delete_messages administrator right.
+    /// TDLib supports only [user](crate::types::MessageSenderUser) senders, [RTDError::BadRequest](crate::errors::RTDError::BadRequest) returned for chats.
+    pub async fn delete_messages_by_sender(
+        &self,
+        chat_id: i64,
+        sender: MessageSender,
+    ) -> RTDResult<()> {
+        let user_id = match sender {
+            MessageSender::User(user) => user.user_id(),
+            _ => {
+                return Err(RTDError::BadRequest(
+                    "only messages sent by users can be deleted by sender",
+                ))
+            }
+        };
+        self.delete_chat_messages_from_user(
+            DeleteChatMessagesFromUser::builder()
+                .chat_id(chat_id)
+                .user_id(user_id)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // Waits until temporary message returned by send request is actually sent, returns the final message
+    async fn wait_message_sent(
+        &self,
+        updates: &mut broadcast::Receiver<Box<Update>>,
+        message: Message,
+    ) -> RTDResult<Message> {
+        if message.sending_state().is_none() {
+            return Ok(message);
+        }
+        loop {
+            match updates.recv().await {
+                Ok(update) => match *update {
+                    Update::MessageSendSucceeded(sent)
+                        if sent.old_message_id() == message.id()
+                            && sent.message().chat_id() == message.chat_id() =>
+                    {
+                        return Ok(sent.message().clone())
+                    }
+                    Update::MessageSendFailed(failed)
+                        if failed.old_message_id() == message.id()
+                            && failed.message().chat_id() == message.chat_id() =>
+                    {
+                        return Err(RTDError::TDLibError(
+                            Error::builder()
+                                .code(failed.error_code())
+                                .message(failed.error_message())
+                                .build(),
+                        ))
+                    }
+                    _ => {}
+                },
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("{} updates skipped while waiting for sent message", skipped)
+                }
+                Err(broadcast::error::RecvError::Closed) => return Err(CLOSED_RECEIVER_ERROR),
+            }
+        }
+    }
+
+    // Adds the chat to the chat list, removing it from Main or Archive list if needed
+    async fn move_chat_to_list(&self, chat_id: i64, chat_list: ChatList) -> RTDResult<()> {
+        self.add_chat_to_list(
+            AddChatToList::builder()
+                .chat_id(chat_id)
+                .chat_list(chat_list)
+                .build(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // Resolves identifier of the supergroup (or channel) behind the chat.
+    async fn supergroup_id(&self, chat_id: i64) -> RTDResult<i32> {
+        let chat = self
+            .get_chat(GetChat::builder().chat_id(chat_id).build())
+            .await?;
+        match chat.type_() {
+            ChatType::Supergroup(supergroup) => Ok(supergroup.supergroup_id()),
+            _ => Err(RTDError::BadRequest("chat is not a supergroup or channel")),
+        }
+    }
+
+    // Returns status of current user in the chat.
+    async fn my_member_status(&self, chat_id: i64) -> RTDResult<ChatMemberStatus> {
+        let me = self.get_me(GetMe::builder().build()).await?;
+        let member = self
+            .get_chat_member(
+                GetChatMember::builder()
+                    .chat_id(chat_id)
+                    .user_id(me.id())
+                    .build(),
+            )
+            .await?;
+        Ok(member.status().clone())
+    }
+
     // Accepts an incoming call
     pub async fn accept_call<C: AsRef<AcceptCall>>(&self, accept_call: C) -> RTDResult<Ok> {
         let extra = accept_call.as_ref().extra().ok_or(NO_EXTRA)?;
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, accept_call.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -236,7 +2339,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, accept_terms_of_service.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -260,7 +2363,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_chat_member.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -284,7 +2387,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_chat_members.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -308,7 +2411,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_chat_to_list.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -329,7 +2432,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_contact.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -358,7 +2461,7 @@ where
             self.get_client_id()?,
             add_custom_server_language_pack.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -382,7 +2485,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_favorite_sticker.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -406,7 +2509,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_local_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -430,7 +2533,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_log_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -454,7 +2557,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_network_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -475,7 +2578,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -499,7 +2602,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_recent_sticker.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -523,7 +2626,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_recently_found_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -547,7 +2650,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_saved_animation.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -571,7 +2674,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, add_sticker_to_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -595,7 +2698,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, answer_callback_query.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -619,7 +2722,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, answer_custom_query.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -643,7 +2746,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, answer_inline_query.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -667,7 +2770,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, answer_pre_checkout_query.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -691,7 +2794,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, answer_shipping_query.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -720,7 +2823,7 @@ where
             self.get_client_id()?,
             block_message_sender_from_replies.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -744,7 +2847,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, can_transfer_ownership.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -768,7 +2871,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, cancel_download_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -792,7 +2895,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, cancel_upload_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -816,7 +2919,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, change_imported_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -840,7 +2943,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, change_phone_number.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -864,7 +2967,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, change_sticker_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -893,7 +2996,7 @@ where
             self.get_client_id()?,
             check_authentication_bot_token.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -917,7 +3020,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, check_authentication_code.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -946,7 +3049,7 @@ where
             self.get_client_id()?,
             check_authentication_password.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -975,7 +3078,7 @@ where
             self.get_client_id()?,
             check_change_phone_number_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -999,7 +3102,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, check_chat_invite_link.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1023,7 +3126,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, check_chat_username.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1052,7 +3155,7 @@ where
             self.get_client_id()?,
             check_created_public_chats_limit.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1081,7 +3184,7 @@ where
             self.get_client_id()?,
             check_database_encryption_key.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1112,7 +3215,7 @@ where
             self.get_client_id()?,
             check_email_address_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1143,7 +3246,7 @@ where
             self.get_client_id()?,
             check_phone_number_confirmation_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1174,7 +3277,7 @@ where
             self.get_client_id()?,
             check_phone_number_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1203,7 +3306,7 @@ where
             self.get_client_id()?,
             check_recovery_email_address_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1227,7 +3330,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, clean_file_name.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1251,7 +3354,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, clear_all_draft_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1275,7 +3378,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, clear_imported_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1299,7 +3402,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, clear_recent_stickers.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1326,7 +3429,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, clear_recently_found_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1347,7 +3450,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, close.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1368,7 +3471,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, close_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1392,7 +3495,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, close_secret_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1421,7 +3524,7 @@ where
             self.get_client_id()?,
             confirm_qr_code_authentication.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1445,7 +3548,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_basic_group_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1466,7 +3569,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_call.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1490,7 +3593,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_chat_filter.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1517,7 +3620,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_new_basic_group_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1541,7 +3644,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_new_secret_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1565,7 +3668,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_new_sticker_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1592,7 +3695,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_new_supergroup_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1616,7 +3719,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_private_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1640,7 +3743,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_secret_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1664,7 +3767,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_supergroup_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1688,7 +3791,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, create_temporary_password.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1712,7 +3815,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_account.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1736,7 +3839,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_chat_filter.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1760,7 +3863,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_chat_history.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1789,7 +3892,7 @@ where
             self.get_client_id()?,
             delete_chat_messages_from_user.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1813,7 +3916,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_chat_reply_markup.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1834,7 +3937,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1858,7 +3961,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_language_pack.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1882,7 +3985,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1906,7 +4009,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_passport_element.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1930,7 +4033,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_profile_photo.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1954,7 +4057,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_saved_credentials.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -1978,7 +4081,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_saved_order_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2002,7 +4105,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, delete_supergroup.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2023,7 +4126,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, destroy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2044,7 +4147,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, disable_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2065,7 +4168,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, discard_call.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2089,7 +4192,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, disconnect_all_websites.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2113,7 +4216,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, disconnect_website.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2134,7 +4237,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, download_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2158,7 +4261,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_chat_filter.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2187,7 +4290,7 @@ where
             self.get_client_id()?,
             edit_custom_language_pack_info.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2214,7 +4317,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_inline_message_caption.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2243,7 +4346,7 @@ where
             self.get_client_id()?,
             edit_inline_message_live_location.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2267,7 +4370,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_inline_message_media.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2296,7 +4399,7 @@ where
             self.get_client_id()?,
             edit_inline_message_reply_markup.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2320,7 +4423,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_inline_message_text.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2344,7 +4447,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_message_caption.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2371,7 +4474,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_message_live_location.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2395,7 +4498,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_message_media.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2419,7 +4522,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_message_reply_markup.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2448,7 +4551,7 @@ where
             self.get_client_id()?,
             edit_message_scheduling_state.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2472,7 +4575,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_message_text.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2493,7 +4596,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, edit_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2514,7 +4617,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, enable_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2538,7 +4641,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, finish_file_generation.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2562,7 +4665,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, forward_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2586,7 +4689,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, generate_chat_invite_link.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2610,7 +4713,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_account_ttl.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2639,7 +4742,7 @@ where
             self.get_client_id()?,
             get_active_live_location_messages.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2663,7 +4766,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_active_sessions.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2687,7 +4790,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_all_passport_elements.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2711,7 +4814,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_application_config.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2735,7 +4838,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_archived_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2759,7 +4862,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_attached_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2783,7 +4886,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_authorization_state.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2812,7 +4915,7 @@ where
             self.get_client_id()?,
             get_auto_download_settings_presets.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2836,7 +4939,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_background_url.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2860,7 +4963,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_backgrounds.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2884,7 +4987,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_bank_card_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2908,7 +5011,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_basic_group.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2932,7 +5035,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_basic_group_full_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2959,7 +5062,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_blocked_message_senders.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -2983,7 +5086,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_callback_query_answer.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3010,7 +5113,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_callback_query_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3031,7 +5134,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3055,7 +5158,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_administrators.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3079,7 +5182,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_event_log.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3103,7 +5206,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_filter.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3132,7 +5235,7 @@ where
             self.get_client_id()?,
             get_chat_filter_default_icon_name.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3156,7 +5259,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_history.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3183,7 +5286,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_lists_to_add_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3207,7 +5310,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_member.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3231,7 +5334,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_message_by_date.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3255,7 +5358,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_message_count.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3286,7 +5389,7 @@ where
             self.get_client_id()?,
             get_chat_notification_settings_exceptions.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3310,7 +5413,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_pinned_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3337,7 +5440,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_scheduled_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3361,7 +5464,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3385,7 +5488,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chat_statistics_url.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3406,7 +5509,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3430,7 +5533,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_connected_websites.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3451,7 +5554,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3475,7 +5578,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_countries.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3499,7 +5602,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_country_code.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3523,7 +5626,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_created_public_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3547,7 +5650,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_current_state.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3571,7 +5674,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_database_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3595,7 +5698,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_deep_link_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3619,7 +5722,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_emoji_suggestions_url.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3643,7 +5746,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_favorite_stickers.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3664,7 +5767,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3693,7 +5796,7 @@ where
             self.get_client_id()?,
             get_file_downloaded_prefix_size.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3717,7 +5820,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_file_extension.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3741,7 +5844,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_file_mime_type.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3765,7 +5868,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_game_high_scores.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3789,7 +5892,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_groups_in_common.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3816,7 +5919,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_imported_contact_count.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3845,7 +5948,7 @@ where
             self.get_client_id()?,
             get_inactive_supergroup_chats.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3872,7 +5975,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_inline_game_high_scores.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3896,7 +5999,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_inline_query_results.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3923,7 +6026,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_installed_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3947,7 +6050,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_invite_text.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3971,7 +6074,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_json_string.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -3995,7 +6098,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_json_value.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4019,7 +6122,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_language_pack_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4043,7 +6146,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_language_pack_string.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4067,7 +6170,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_language_pack_strings.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4094,7 +6197,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_localization_target_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4118,7 +6221,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_log_stream.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4145,7 +6248,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_log_tag_verbosity_level.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4166,7 +6269,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_log_tags.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4190,7 +6293,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_log_verbosity_level.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4214,7 +6317,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_login_url.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4238,7 +6341,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_login_url_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4262,7 +6365,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_map_thumbnail_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4286,7 +6389,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_markdown_text.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4307,7 +6410,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_me.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4328,7 +6431,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4355,7 +6458,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_embedding_code.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4379,7 +6482,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_link.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4403,7 +6506,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_link_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4427,7 +6530,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_locally.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4454,7 +6557,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_public_forwards.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4478,7 +6581,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4502,7 +6605,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_thread.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4529,7 +6632,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_message_thread_history.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4553,7 +6656,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4577,7 +6680,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_network_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4598,7 +6701,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_option.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4627,7 +6730,7 @@ where
             self.get_client_id()?,
             get_passport_authorization_form.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4658,7 +6761,7 @@ where
             self.get_client_id()?,
             get_passport_authorization_form_available_elements.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4682,7 +6785,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_passport_element.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4706,7 +6809,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_password_state.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4730,7 +6833,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_payment_form.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4754,7 +6857,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_payment_receipt.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4778,7 +6881,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_phone_number_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4802,7 +6905,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_poll_voters.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4831,7 +6934,7 @@ where
             self.get_client_id()?,
             get_preferred_country_language.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4852,7 +6955,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_proxies.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4876,7 +6979,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_proxy_link.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4900,7 +7003,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_push_receiver_id.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4924,7 +7027,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_recent_inline_bots.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4948,7 +7051,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_recent_stickers.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -4977,7 +7080,7 @@ where
             self.get_client_id()?,
             get_recently_visited_t_me_urls.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5004,7 +7107,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_recommended_chat_filters.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5031,7 +7134,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_recovery_email_address.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5055,7 +7158,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_remote_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5079,7 +7182,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_replied_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5103,7 +7206,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_saved_animations.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5127,7 +7230,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_saved_order_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5156,7 +7259,7 @@ where
             self.get_client_id()?,
             get_scope_notification_settings.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5180,7 +7283,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_secret_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5204,7 +7307,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_statistical_graph.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5228,7 +7331,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_sticker_emojis.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5252,7 +7355,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_sticker_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5276,7 +7379,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_stickers.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5300,7 +7403,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_storage_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5327,7 +7430,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_storage_statistics_fast.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5356,7 +7459,7 @@ where
             self.get_client_id()?,
             get_suitable_discussion_chats.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5380,7 +7483,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_supergroup.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5404,7 +7507,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_supergroup_full_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5428,7 +7531,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_supergroup_members.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5452,7 +7555,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_support_user.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5479,7 +7582,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_temporary_password_state.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5503,7 +7606,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_text_entities.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5524,7 +7627,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_top_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5548,7 +7651,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_trending_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5569,7 +7672,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_user.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5593,7 +7696,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_user_full_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5622,7 +7725,7 @@ where
             self.get_client_id()?,
             get_user_privacy_setting_rules.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5646,7 +7749,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_user_profile_photos.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5670,7 +7773,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_web_page_instant_view.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5694,7 +7797,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, get_web_page_preview.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5718,7 +7821,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, hide_suggested_action.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5742,7 +7845,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, import_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5763,7 +7866,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, join_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5787,7 +7890,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, join_chat_by_invite_link.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5808,7 +7911,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, leave_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5829,7 +7932,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, log_out.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5850,7 +7953,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, open_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5874,7 +7977,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, open_message_content.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5898,7 +8001,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, optimize_storage.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5922,7 +8025,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, parse_markdown.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5946,7 +8049,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, parse_text_entities.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5970,7 +8073,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, pin_chat_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -5991,7 +8094,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, ping_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6015,7 +8118,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, process_push_notification.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6039,7 +8142,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, read_all_chat_mentions.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6063,7 +8166,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, read_file_part.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6092,7 +8195,7 @@ where
             self.get_client_id()?,
             recover_authentication_password.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6116,7 +8219,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, recover_password.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6140,7 +8243,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, register_device.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6161,7 +8264,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, register_user.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6185,7 +8288,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_background.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6209,7 +8312,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_chat_action_bar.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6233,7 +8336,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6257,7 +8360,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_favorite_sticker.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6281,7 +8384,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_notification.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6305,7 +8408,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_notification_group.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6326,7 +8429,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6350,7 +8453,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_recent_hashtag.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6374,7 +8477,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_recent_sticker.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6401,7 +8504,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_recently_found_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6425,7 +8528,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_saved_animation.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6449,7 +8552,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_sticker_from_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6473,7 +8576,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, remove_top_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6497,7 +8600,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, reorder_chat_filters.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6526,7 +8629,7 @@ where
             self.get_client_id()?,
             reorder_installed_sticker_sets.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6547,7 +8650,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, report_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6571,7 +8674,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, report_supergroup_spam.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6602,7 +8705,7 @@ where
             self.get_client_id()?,
             request_authentication_password_recovery.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6626,7 +8729,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, request_password_recovery.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6655,7 +8758,7 @@ where
             self.get_client_id()?,
             request_qr_code_authentication.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6682,7 +8785,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, resend_authentication_code.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6711,7 +8814,7 @@ where
             self.get_client_id()?,
             resend_change_phone_number_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6742,7 +8845,7 @@ where
             self.get_client_id()?,
             resend_email_address_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6766,7 +8869,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, resend_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6797,7 +8900,7 @@ where
             self.get_client_id()?,
             resend_phone_number_confirmation_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6828,7 +8931,7 @@ where
             self.get_client_id()?,
             resend_phone_number_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6857,7 +8960,7 @@ where
             self.get_client_id()?,
             resend_recovery_email_address_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6886,7 +8989,7 @@ where
             self.get_client_id()?,
             reset_all_notification_settings.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6910,7 +9013,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, reset_backgrounds.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6934,7 +9037,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, reset_network_statistics.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6961,7 +9064,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, save_application_log_event.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -6985,7 +9088,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_background.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7009,7 +9112,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_call_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7033,7 +9136,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_chat_members.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7057,7 +9160,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_chat_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7088,7 +9191,7 @@ where
             self.get_client_id()?,
             search_chat_recent_location_messages.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7109,7 +9212,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7133,7 +9236,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_chats_nearby.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7157,7 +9260,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_chats_on_server.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7181,7 +9284,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_contacts.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7205,7 +9308,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_emojis.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7229,7 +9332,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_hashtags.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7258,7 +9361,7 @@ where
             self.get_client_id()?,
             search_installed_sticker_sets.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7282,7 +9385,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7306,7 +9409,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_public_chat.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7330,7 +9433,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_public_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7354,7 +9457,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_secret_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7378,7 +9481,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_sticker_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7402,7 +9505,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7426,7 +9529,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, search_stickers.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7450,7 +9553,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_bot_start_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7477,7 +9580,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_call_debug_information.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7501,7 +9604,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_call_rating.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7525,7 +9628,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_call_signaling_data.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7549,7 +9652,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_chat_action.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7580,7 +9683,7 @@ where
             self.get_client_id()?,
             send_chat_screenshot_taken_notification.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7604,7 +9707,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_chat_set_ttl_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7628,7 +9731,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_custom_request.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7659,7 +9762,7 @@ where
             self.get_client_id()?,
             send_email_address_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7688,7 +9791,7 @@ where
             self.get_client_id()?,
             send_inline_query_result_message.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7709,7 +9812,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7733,7 +9836,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_message_album.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7762,7 +9865,7 @@ where
             self.get_client_id()?,
             send_passport_authorization_form.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7786,7 +9889,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, send_payment_form.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7815,7 +9918,7 @@ where
             self.get_client_id()?,
             send_phone_number_confirmation_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7844,7 +9947,7 @@ where
             self.get_client_id()?,
             send_phone_number_verification_code.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7868,7 +9971,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_account_ttl.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7889,7 +9992,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_alarm.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7918,7 +10021,7 @@ where
             self.get_client_id()?,
             set_authentication_phone_number.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7945,7 +10048,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_auto_download_settings.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7969,7 +10072,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_background.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -7990,7 +10093,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_bio.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8014,7 +10117,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_bot_updates_status.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8038,7 +10141,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_client_data.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8062,7 +10165,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_description.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8086,7 +10189,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_discussion_group.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8110,7 +10213,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_draft_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8134,7 +10237,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_location.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8158,7 +10261,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_member_status.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8187,7 +10290,7 @@ where
             self.get_client_id()?,
             set_chat_notification_settings.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8211,7 +10314,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_permissions.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8232,7 +10335,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_photo.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8256,7 +10359,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_slow_mode_delay.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8277,7 +10380,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_chat_title.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8298,7 +10401,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_commands.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8322,7 +10425,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_custom_language_pack.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8351,7 +10454,7 @@ where
             self.get_client_id()?,
             set_custom_language_pack_string.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8378,7 +10481,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_database_encryption_key.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8405,7 +10508,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_file_generation_progress.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8429,7 +10532,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_game_score.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8453,7 +10556,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_inline_game_score.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8474,7 +10577,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_location.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8495,7 +10598,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_log_stream.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8522,7 +10625,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_log_tag_verbosity_level.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8546,7 +10649,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_log_verbosity_level.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8567,7 +10670,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_name.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8591,7 +10694,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_network_type.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8612,7 +10715,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_option.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8636,7 +10739,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_passport_element.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8663,7 +10766,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_passport_element_errors.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8687,7 +10790,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_password.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8711,7 +10814,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_pinned_chats.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8735,7 +10838,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_poll_answer.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8759,7 +10862,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_profile_photo.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8786,7 +10889,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_recovery_email_address.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8815,7 +10918,7 @@ where
             self.get_client_id()?,
             set_scope_notification_settings.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8842,7 +10945,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_sticker_position_in_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8866,7 +10969,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_sticker_set_thumbnail.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8893,7 +10996,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_supergroup_sticker_set.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8917,7 +11020,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_supergroup_username.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8941,7 +11044,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_tdlib_parameters.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8970,7 +11073,7 @@ where
             self.get_client_id()?,
             set_user_privacy_setting_rules.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -8991,7 +11094,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, set_username.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9015,7 +11118,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, share_phone_number.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9036,7 +11139,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, stop_poll.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9060,7 +11163,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, synchronize_language_pack.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9087,7 +11190,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, terminate_all_other_sessions.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9111,7 +11214,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, terminate_session.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9135,7 +11238,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_bytes.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9159,7 +11262,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_empty.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9183,7 +11286,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_string.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9207,7 +11310,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_vector_int.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9234,7 +11337,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_vector_int_object.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9258,7 +11361,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_call_vector_string.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9287,7 +11390,7 @@ where
             self.get_client_id()?,
             test_call_vector_string_object.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9311,7 +11414,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_get_difference.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9332,7 +11435,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_network.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9353,7 +11456,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_proxy.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9377,7 +11480,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_return_error.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9401,7 +11504,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_square_int.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9425,7 +11528,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, test_use_update.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9456,7 +11559,7 @@ where
             self.get_client_id()?,
             toggle_chat_default_disable_notification.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9485,7 +11588,7 @@ where
             self.get_client_id()?,
             toggle_chat_is_marked_as_unread.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9509,7 +11612,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, toggle_chat_is_pinned.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9538,7 +11641,7 @@ where
             self.get_client_id()?,
             toggle_message_sender_is_blocked.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9569,7 +11672,7 @@ where
             self.get_client_id()?,
             toggle_supergroup_is_all_history_available.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9598,7 +11701,7 @@ where
             self.get_client_id()?,
             toggle_supergroup_sign_messages.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9622,7 +11725,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, transfer_chat_ownership.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9646,7 +11749,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, unpin_all_chat_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9670,7 +11773,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, unpin_chat_message.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9701,7 +11804,7 @@ where
             self.get_client_id()?,
             upgrade_basic_group_chat_to_supergroup_chat.as_ref(),
         )?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9722,7 +11825,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, upload_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9746,7 +11849,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, upload_sticker_file.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9770,7 +11873,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, validate_order_info.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9791,7 +11894,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, view_messages.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9818,7 +11921,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, view_trending_sticker_sets.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9842,7 +11945,7 @@ where
         let signal = OBSERVER.subscribe(&extra);
         self.tdlib_client
             .send(self.get_client_id()?, write_generated_file_part.as_ref())?;
-        let received = signal.await;
+        let received = self.tdlib_client.receive(signal).await?;
         OBSERVER.unsubscribe(&extra);
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
@@ -9857,3 +11960,45 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::split_text;
+    use crate::client::client::Client;
+    use crate::client::MockTdLibClient;
+    use crate::errors::RTDError;
+    use crate::types::{GetApplicationConfig, TdlibParameters};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        // no response enqueued, so the request is never answered
+        let mut client = Client::builder()
+            .with_tdlib_client(MockTdLibClient::new())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .with_request_timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        client.set_client_id(1).unwrap();
+
+        match client
+            .get_application_config(GetApplicationConfig::builder().build())
+            .await
+        {
+            Err(RTDError::RequestTimeout) => {}
+            Err(e) => panic!("{}", e),
+            Ok(_) => panic!("error not raised"),
+        }
+    }
+
+    #[test]
+    fn test_split_text() {
+        assert_eq!(split_text("", 5), Vec::<&str>::new());
+        assert_eq!(split_text("hello", 5), vec!["hello"]);
+        assert_eq!(split_text("hello world", 8), vec!["hello ", "world"]);
+        assert_eq!(split_text("ab\ncd ef", 7), vec!["ab\n", "cd ef"]);
+        assert_eq!(split_text("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        // emoji takes two UTF-16 code units
+        assert_eq!(split_text("😀😀😀", 4), vec!["😀😀", "😀"]);
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Outgoing request recorded by [Worker](crate::client::Worker) when request history is enabled, see [with_request_history](crate::client::worker::WorkerBuilder::with_request_history).
+#[derive(Debug, Clone)]
+pub struct RequestRecord {
+    client_id: i32,
+    extra: String,
+    request: String,
+    response: Option<String>,
+}
+
+impl RequestRecord {
+    /// Identifier of the client which sent the request.
+    pub fn client_id(&self) -> i32 {
+        self.client_id
+    }
+
+    /// `@extra` field of the request.
+    pub fn extra(&self) -> &str {
+        &self.extra
+    }
+
+    /// Request JSON, as sent to TDLib.
+    pub fn request(&self) -> &str {
+        &self.request
+    }
+
+    /// Response or error JSON, as received from TDLib. None if the response not received yet.
+    pub fn response(&self) -> Option<&str> {
+        self.response.as_deref()
+    }
+}
+
+/// Ring buffer of the last sent requests.
+#[derive(Debug)]
+pub(crate) struct RequestHistory {
+    capacity: usize,
+    records: Mutex<VecDeque<RequestRecord>>,
+}
+
+impl RequestHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub fn record_request(&self, client_id: i32, extra: &str, request: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap();
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(RequestRecord {
+            client_id,
+            extra: extra.to_string(),
+            request,
+            response: None,
+        });
+    }
+
+    pub fn record_response(&self, extra: &str, response: &str) {
+        let mut records = self.records.lock().unwrap();
+        if let Some(record) = records.iter_mut().rev().find(|r| r.extra == extra) {
+            record.response = Some(response.to_string());
+        }
+    }
+
+    pub fn records(&self) -> Vec<RequestRecord> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+}
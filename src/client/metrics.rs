@@ -0,0 +1,64 @@
+use crate::errors::RTDError;
+use crate::tdjson::ClientId;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Hooks called by [Worker](crate::client::Worker) and bound [clients](crate::client::Client) to collect metrics, see [with_metrics](crate::client::WorkerBuilder::with_metrics).
+/// All methods do nothing by default, so implement only the required ones.
+/// Hooks are called synchronously from the updates loop and request path, so they should be cheap, like incrementing a counter.
+///
+/// For example, with the `metrics` crate and a Prometheus recorder (`metrics-exporter-prometheus`) installed:
+/// ```ignore
+/// #[derive(Debug)]
+/// struct PrometheusMetrics;
+///
+/// impl WorkerMetrics for PrometheusMetrics {
+///     fn on_update_received(&self, td_type: &str) {
+///         metrics::counter!("tdlib_updates_total", "type" => td_type.to_string()).increment(1);
+///     }
+///     fn on_request_sent(&self, _client_id: ClientId) {
+///         metrics::counter!("tdlib_requests_total").increment(1);
+///     }
+///     fn on_response_received(&self, _client_id: ClientId, elapsed: Duration) {
+///         metrics::histogram!("tdlib_response_seconds").record(elapsed.as_secs_f64());
+///     }
+///     fn on_error(&self, _error: &RTDError) {
+///         metrics::counter!("tdlib_errors_total").increment(1);
+///     }
+/// }
+///
+/// let worker = Worker::builder().with_metrics(PrometheusMetrics).build()?;
+/// ```
+pub trait WorkerMetrics: Send + Sync {
+    /// Called for each update received from TDLib, with its `@type`, e.g. `updateNewMessage`.
+    fn on_update_received(&self, _td_type: &str) {}
+    /// Called when the client sent a request to TDLib.
+    fn on_request_sent(&self, _client_id: ClientId) {}
+    /// Called when the client received response to the request, including TDLib errors, `elapsed` is time since the request sent.
+    fn on_response_received(&self, _client_id: ClientId, _elapsed: Duration) {}
+    /// Called when the request failed, or data received from TDLib can't be deserialized.
+    fn on_error(&self, _error: &RTDError) {}
+}
+
+// Metrics shared between the worker and its clients, wrapped to keep them debuggable.
+#[derive(Clone)]
+pub(crate) struct Metrics(pub Arc<dyn WorkerMetrics>);
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Metrics")
+    }
+}
+
+#[derive(Deserialize)]
+struct Typed<'a> {
+    #[serde(rename = "@type")]
+    td_type: &'a str,
+}
+
+// Extracts `@type` of received JSON without deserializing the whole object
+pub(crate) fn td_type(json: &str) -> Option<&str> {
+    serde_json::from_str::<Typed>(json)
+        .ok()
+        .map(|typed| typed.td_type)
+}
@@ -0,0 +1,733 @@
+use super::tdlib_client::TdLibClient;
+use crate::errors::RTDResult;
+use crate::tdjson::ClientId;
+use crate::types::RFunction;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses_by_extra: HashMap<String, Value>,
+    responses_by_type: HashMap<String, VecDeque<Value>>,
+    to_receive: VecDeque<String>,
+    sent: Vec<(ClientId, Value)>,
+    executed: Vec<Value>,
+    last_client_id: ClientId,
+}
+
+impl MockState {
+    // Returns enqueued response for the request, with `@extra` copied from the request
+    fn take_response(&mut self, request: &Value) -> Option<Value> {
+        let extra = request.get("@extra").and_then(Value::as_str);
+        let mut response = match extra.and_then(|extra| self.responses_by_extra.remove(extra)) {
+            Some(response) => response,
+            None => {
+                let request_type = request.get("@type").and_then(Value::as_str)?;
+                self.responses_by_type.get_mut(request_type)?.pop_front()?
+            }
+        };
+        if let (Some(extra), Some(object)) = (extra, response.as_object_mut()) {
+            object.insert("@extra".to_string(), Value::String(extra.to_string()));
+        }
+        Some(response)
+    }
+}
+
+/// [TdLibClient](crate::client::tdlib_client::TdLibClient) which responds with canned JSON instead of TDLib and records all requests.
+/// Allows to test code interacting with [Client](crate::client::Client) and [Worker](crate::client::Worker) without real TDLib,
+/// pass it to [ClientBuilder::with_tdlib_client](crate::client::client::ClientBuilder::with_tdlib_client) and [WorkerBuilder::with_tdlib_client](crate::client::WorkerBuilder::with_tdlib_client).
+/// Clones share enqueued responses and recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockTdLibClient {
+    state: Arc<(Mutex<MockState>, Condvar)>,
+}
+
+impl MockTdLibClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enqueues response to the next request with specified `@type`, e.g. `getMe`. Responses to the same type are returned in order.
+    /// `@extra` and `@client_id` of the response are filled from the request.
+    pub fn enqueue_response<T: AsRef<str>>(&self, request_type: T, response: Value) {
+        let mut state = self.state.0.lock().unwrap();
+        state
+            .responses_by_type
+            .entry(request_type.as_ref().to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Enqueues response to the request with specified `@extra`, it takes precedence over responses enqueued by `@type`.
+    pub fn enqueue_response_for_extra<T: AsRef<str>>(&self, extra: T, response: Value) {
+        let mut state = self.state.0.lock().unwrap();
+        state
+            .responses_by_extra
+            .insert(extra.as_ref().to_string(), response);
+    }
+
+    /// Sends update, e.g. [updateNewMessage](crate::types::UpdateNewMessage), to the client as if it's received from TDLib.
+    pub fn push_update(&self, client_id: ClientId, mut update: Value) {
+        if let Some(object) = update.as_object_mut() {
+            object.insert("@client_id".to_string(), Value::from(client_id));
+        }
+        self.push_received(update.to_string());
+    }
+
+    /// Returns all requests sent with [send](crate::client::tdlib_client::TdLibClient::send), in order, along with client identifiers.
+    pub fn sent_requests(&self) -> Vec<(ClientId, Value)> {
+        self.state.0.lock().unwrap().sent.clone()
+    }
+
+    /// Returns all requests sent with [execute](crate::client::tdlib_client::TdLibClient::execute), in order.
+    pub fn executed_requests(&self) -> Vec<Value> {
+        self.state.0.lock().unwrap().executed.clone()
+    }
+
+    /// Sends data to the worker as is, as if it's received from TDLib, e.g. to test handling of data which can't be deserialized.
+    pub fn push_received<T: Into<String>>(&self, json: T) {
+        let json = json.into();
+        let (state, received) = &*self.state;
+        state.lock().unwrap().to_receive.push_back(json);
+        received.notify_all();
+    }
+}
+
+impl TdLibClient for MockTdLibClient {
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> RTDResult<()> {
+        let request: Value = serde_json::from_str(&fnc.to_json()?)?;
+        let response = {
+            let mut state = self.state.0.lock().unwrap();
+            state.sent.push((client_id, request.clone()));
+            state.take_response(&request)
+        };
+        if let Some(response) = response {
+            self.push_update(client_id, response);
+        }
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let (state, received) = &*self.state;
+        let (mut state, _) = received
+            .wait_timeout_while(
+                state.lock().unwrap(),
+                Duration::from_secs_f64(timeout),
+                |state| state.to_receive.is_empty(),
+            )
+            .unwrap();
+        state.to_receive.pop_front()
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<Option<String>> {
+        let request: Value = serde_json::from_str(&fnc.to_json()?)?;
+        let mut state = self.state.0.lock().unwrap();
+        state.executed.push(request.clone());
+        Ok(state
+            .take_response(&request)
+            .map(|response| response.to_string()))
+    }
+
+    fn new_client(&self) -> ClientId {
+        let mut state = self.state.0.lock().unwrap();
+        state.last_client_id += 1;
+        state.last_client_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{
+        BindOptions, BotAuthStateHandler, Client, ClientState, ConsoleAuthStateHandler,
+        MockTdLibClient, Worker,
+    };
+    use crate::errors::RTDError;
+    use crate::types::{
+        ExtraGenerator, GetFileMimeType, GetOption, OptionValue, SequentialExtraGenerator,
+        TdlibParameters, TextEntityType,
+    };
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_mock_tdlib_client() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "getApplicationConfig",
+            serde_json::json!({"@type": "jsonValueNull"}),
+        );
+        mock.enqueue_response(
+            "getOption",
+            serde_json::json!({"@type": "optionValueInteger", "value": "42"}),
+        );
+
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .bind_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let option = timeout(
+            Duration::from_secs(10),
+            client.get_option(GetOption::builder().name("my_id").build()),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        match option {
+            OptionValue::Integer(value) => assert_eq!(value.value(), 42),
+            _ => panic!("invalid option value: {:?}", option),
+        }
+
+        let sent = mock.sent_requests();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1].0, 1);
+        assert_eq!(sent[1].1["@type"], "getOption");
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_extra_generator() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "getApplicationConfig",
+            serde_json::json!({"@type": "jsonValueNull"}),
+        );
+        mock.enqueue_response_for_extra(
+            "1",
+            serde_json::json!({"@type": "optionValueInteger", "value": "42"}),
+        );
+
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .bind_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_extra_generator(SequentialExtraGenerator::default())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        timeout(
+            Duration::from_secs(10),
+            client.get_option(GetOption::builder().name("my_id").build()),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(mock.sent_requests()[1].1["@extra"], "1");
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_flood_wait() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "getApplicationConfig",
+            serde_json::json!({"@type": "jsonValueNull"}),
+        );
+        mock.enqueue_response(
+            "getOption",
+            serde_json::json!({"@type": "error", "code": 429, "message": "Too Many Requests: retry after 30"}),
+        );
+
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .bind_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        match timeout(
+            Duration::from_secs(10),
+            client.get_option(GetOption::builder().name("my_id").build()),
+        )
+        .await
+        .unwrap()
+        {
+            Err(RTDError::FloodWait { retry_after: 30 }) => {}
+            result => panic!("flood wait not returned: {:?}", result),
+        }
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_bind_client_with_options() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "getApplicationConfig",
+            serde_json::json!({"@type": "jsonValueNull"}),
+        );
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        worker.start();
+        let new_client = || {
+            Client::builder()
+                .with_tdlib_client(mock.clone())
+                .with_tdlib_parameters(TdlibParameters::builder().build())
+                .build()
+                .unwrap()
+        };
+
+        let options = BindOptions::default()
+            .with_label("main")
+            .with_auth_state_handler(ConsoleAuthStateHandler::new());
+        let client = worker
+            .bind_client_with_options(new_client(), options.clone())
+            .await
+            .unwrap();
+        assert!(worker
+            .bind_client_with_options(new_client(), options)
+            .await
+            .is_err());
+        let found = worker.client_by_label("main").await.unwrap();
+        assert_eq!(
+            found.get_client_id().unwrap(),
+            client.get_client_id().unwrap()
+        );
+        worker.stop();
+    }
+
+    fn file_json(downloaded_size: i32, is_completed: bool) -> serde_json::Value {
+        serde_json::json!({
+            "@type": "file",
+            "id": 7,
+            "size": 100,
+            "expected_size": 100,
+            "local": {
+                "@type": "localFile",
+                "path": if is_completed { "/tmp/file" } else { "" },
+                "can_be_downloaded": true,
+                "can_be_deleted": false,
+                "is_downloading_active": !is_completed,
+                "is_downloading_completed": is_completed,
+                "download_offset": 0,
+                "downloaded_prefix_size": downloaded_size,
+                "downloaded_size": downloaded_size,
+            },
+            "remote": {
+                "@type": "remoteFile",
+                "id": "remote",
+                "unique_id": "unique",
+                "is_uploading_active": false,
+                "is_uploading_completed": true,
+                "uploaded_size": 100,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_download_file_stream() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response("downloadFile", file_json(0, false));
+        let client = Client::builder()
+            .with_tdlib_client(mock.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        let client = worker.set_client(client).await;
+        worker.start();
+
+        let progress = client.download_file_stream(7, 1);
+        futures::pin_mut!(progress);
+        let started = timeout(Duration::from_secs(10), progress.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(started.downloaded_size(), 0);
+        assert_eq!(started.path(), None);
+
+        for (downloaded_size, is_completed) in &[(50, false), (100, true)] {
+            mock.push_update(
+                1,
+                serde_json::json!({
+                    "@type": "updateFile",
+                    "file": file_json(*downloaded_size, *is_completed),
+                }),
+            );
+        }
+        let progress: Vec<_> = timeout(Duration::from_secs(10), progress.collect())
+            .await
+            .unwrap();
+        let progress: Vec<_> = progress.into_iter().map(Result::unwrap).collect();
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress[0].downloaded_size(), 50);
+        assert_eq!(progress[1].expected_size(), 100);
+        assert_eq!(progress[1].path(), Some("/tmp/file"));
+        worker.stop();
+    }
+
+    #[test]
+    fn test_parse_text() {
+        let mock = MockTdLibClient::new();
+        let client = Client::builder()
+            .with_tdlib_client(mock.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        let entity = |offset, length, type_: serde_json::Value| serde_json::json!({"@type": "textEntity", "offset": offset, "length": length, "type": type_});
+        mock.enqueue_response(
+            "parseTextEntities",
+            serde_json::json!({
+                "@type": "formattedText",
+                "text": "bold italic link",
+                "entities": [
+                    entity(0, 4, serde_json::json!({"@type": "textEntityTypeBold"})),
+                    entity(5, 6, serde_json::json!({"@type": "textEntityTypeItalic"})),
+                    entity(12, 4, serde_json::json!({"@type": "textEntityTypeTextUrl", "url": "https://example.com/"})),
+                ],
+            }),
+        );
+        mock.enqueue_response(
+            "parseTextEntities",
+            serde_json::json!({"@type": "error", "code": 400, "message": "Can't find end of the entity"}),
+        );
+
+        let text = client
+            .parse_markdown_v2("*bold* _italic_ [link](https://example.com/)")
+            .unwrap();
+        assert_eq!(text.text(), "bold italic link");
+        let entities: Vec<_> = text
+            .entities()
+            .iter()
+            .map(|entity| (entity.offset(), entity.length(), entity.type_().clone()))
+            .collect();
+        assert!(matches!(entities[0], (0, 4, TextEntityType::Bold(_))));
+        assert!(matches!(entities[1], (5, 6, TextEntityType::Italic(_))));
+        match &entities[2] {
+            (12, 4, TextEntityType::TextUrl(url)) => assert_eq!(url.url(), "https://example.com/"),
+            entity => panic!("invalid entity: {:?}", entity),
+        }
+        assert!(client.parse_html("<b>bold").is_err());
+
+        let executed = mock.executed_requests();
+        assert_eq!(executed[0]["parse_mode"]["@type"], "textParseModeMarkdown");
+        assert_eq!(executed[0]["parse_mode"]["version"], 2);
+        assert_eq!(executed[1]["parse_mode"]["@type"], "textParseModeHTML");
+        assert_eq!(executed[1]["text"], "<b>bold");
+    }
+
+    #[test]
+    fn test_execute() {
+        let mock = MockTdLibClient::new();
+        let client = Client::builder()
+            .with_tdlib_client(mock.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        mock.enqueue_response(
+            "getFileMimeType",
+            serde_json::json!({"@type": "text", "text": "image/png"}),
+        );
+
+        let mime_type = client
+            .execute(GetFileMimeType::builder().file_name("image.png").build())
+            .unwrap();
+        assert_eq!(mime_type.text(), "image/png");
+        assert_eq!(mock.executed_requests()[0]["file_name"], "image.png");
+    }
+
+    #[tokio::test]
+    async fn test_slow_update_subscriber() {
+        let mock = MockTdLibClient::new();
+        let (updates_sx, mut updates_rx) = tokio::sync::mpsc::channel(10);
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .build()
+            .unwrap();
+        let client = worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_updates_sender(updates_sx)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        // never read, so it's full after the first update
+        let _subscriber = client.add_update_subscriber(1);
+        worker.start();
+
+        for name in &["a", "b", "c"] {
+            mock.push_update(
+                1,
+                serde_json::json!({
+                    "@type": "updateOption",
+                    "name": name,
+                    "value": {"@type": "optionValueEmpty"},
+                }),
+            );
+        }
+        for _ in 0..3 {
+            timeout(Duration::from_secs(1), updates_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+        }
+        assert_eq!(worker.dropped_updates(1).await.unwrap(), 2);
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "close",
+            serde_json::json!({
+                "@type": "updateAuthorizationState",
+                "authorization_state": {"@type": "authorizationStateClosed"},
+            }),
+        );
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .with_shutdown_timeout(5.)
+            .build()
+            .unwrap();
+        let client = worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        worker.start();
+        let states = worker
+            .client_state_stream(client.get_client_id().unwrap())
+            .await
+            .unwrap();
+
+        // request of a client not bound to the worker doesn't hold up its shutdown
+        let mut unbound = Client::builder()
+            .with_tdlib_client(mock.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        unbound.set_client_id(42).unwrap();
+        let pending = tokio::spawn(async move {
+            unbound
+                .get_option(GetOption::builder().name("my_id").build())
+                .await
+        });
+        while mock.sent_requests().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        timeout(Duration::from_secs(2), worker.shutdown())
+            .await
+            .unwrap();
+        let states: Vec<_> = timeout(Duration::from_secs(1), states.collect())
+            .await
+            .unwrap();
+        assert_eq!(states.last(), Some(&ClientState::Closed));
+        pending.abort();
+    }
+
+    #[tokio::test]
+    async fn test_duplicated_extra() {
+        struct FixedExtraGenerator;
+
+        impl ExtraGenerator for FixedExtraGenerator {
+            fn generate(&self) -> String {
+                "test_duplicated_extra".to_string()
+            }
+        }
+
+        // no response enqueued, so the first request keeps waiting
+        let mock = MockTdLibClient::new();
+        let new_client = |client_id| {
+            let mut client = Client::builder()
+                .with_tdlib_client(mock.clone())
+                .with_tdlib_parameters(TdlibParameters::builder().build())
+                .with_extra_generator(FixedExtraGenerator)
+                .build()
+                .unwrap();
+            client.set_client_id(client_id).unwrap();
+            client
+        };
+        // ids not used by other tests, as the observer is shared by all of them
+        let (first, second) = (new_client(101), new_client(102));
+        let pending = tokio::spawn(async move {
+            first
+                .get_option(GetOption::builder().name("my_id").build())
+                .await
+        });
+        while mock.sent_requests().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        match second
+            .get_option(GetOption::builder().name("my_id").build())
+            .await
+        {
+            Err(RTDError::BadRequest(_)) => {}
+            result => panic!("duplicated @extra not rejected: {:?}", result),
+        }
+        assert_eq!(mock.sent_requests().len(), 1);
+        pending.abort();
+    }
+
+    fn auth_state_update(state: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({"@type": "updateAuthorizationState", "authorization_state": state})
+    }
+
+    #[tokio::test]
+    async fn test_auto_reconnect_skips_handler_errors() {
+        let mock = MockTdLibClient::new();
+        mock.enqueue_response(
+            "getApplicationConfig",
+            serde_json::json!({"@type": "jsonValueNull"}),
+        );
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .with_auto_reconnect(3, 0.01)
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .bind_client_with_options(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_auth_state_channel(5)
+                    .build()
+                    .unwrap(),
+                BindOptions::default().with_auth_state_handler(BotAuthStateHandler::new("token")),
+            )
+            .await
+            .unwrap();
+
+        // bots don't handle codes, so the handler fails
+        mock.push_update(
+            client.get_client_id().unwrap(),
+            auth_state_update(serde_json::json!({
+                "@type": "authorizationStateWaitCode",
+                "code_info": {
+                    "@type": "authenticationCodeInfo",
+                    "phone_number": "123",
+                    "type": {"@type": "authenticationCodeTypeSms", "length": 5},
+                    "timeout": 60,
+                },
+            })),
+        );
+        match timeout(
+            Duration::from_secs(10),
+            worker.wait_auth_state_change(&client),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        {
+            Err((RTDError::BadRequest(_), _)) => {}
+            state => panic!("handler error not reported: {:?}", state),
+        }
+        assert!(mock
+            .sent_requests()
+            .iter()
+            .all(|(_, request)| request["@type"] != "close"));
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_auto_reconnect_closes_failed_client_first() {
+        let mock = MockTdLibClient::new();
+        for _ in 0..2 {
+            mock.enqueue_response(
+                "getApplicationConfig",
+                serde_json::json!({"@type": "jsonValueNull"}),
+            );
+        }
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .with_auto_reconnect(3, 0.01)
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .bind_client(
+                Client::builder()
+                    .with_tdlib_client(mock.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_request_timeout(Duration::from_millis(50))
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let failed_client_id = client.get_client_id().unwrap();
+
+        // parameters are never accepted, so the request times out
+        mock.push_update(
+            failed_client_id,
+            auth_state_update(
+                serde_json::json!({"@type": "authorizationStateWaitTdlibParameters"}),
+            ),
+        );
+        let is_sent = |request_type: &str| {
+            mock.sent_requests().iter().any(|(client_id, request)| {
+                *client_id == failed_client_id && request["@type"] == request_type
+            })
+        };
+        timeout(Duration::from_secs(10), async {
+            while !is_sent("close") {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        // new client is created only after the failed one is closed
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(client.get_client_id().unwrap(), failed_client_id);
+
+        mock.push_update(
+            failed_client_id,
+            auth_state_update(serde_json::json!({"@type": "authorizationStateClosed"})),
+        );
+        timeout(Duration::from_secs(10), async {
+            while client.get_client_id().unwrap() == failed_client_id {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        worker.stop();
+    }
+}
@@ -1,6 +1,14 @@
 //! Module contains structs and traits, required for proper interaction with Telegram server.
 #[doc(hidden)]
+mod cache;
+mod history;
+mod metrics;
+#[cfg(any(test, feature = "testing"))]
+mod mock;
+#[doc(hidden)]
 mod observer;
+mod retry;
+mod sender;
 
 /// TDlib API methods.
 #[allow(clippy::module_inception)]
@@ -14,6 +22,17 @@ pub mod auth_handler;
 #[doc(hidden)]
 pub mod tdlib_client;
 
-pub use auth_handler::{AuthStateHandler, ConsoleAuthStateHandler, SignalAuthStateHandler};
-pub use client::{Client, ClientState};
-pub use worker::{Worker, WorkerBuilder};
+pub use auth_handler::{
+    AuthStateHandler, BotAuthStateHandler, ClientAuthData, ConsoleAuthStateHandler,
+    SignalAuthStateHandler,
+};
+pub use client::{
+    ChatGuard, Client, ClientState, DownloadProgress, JoinResult, SelfContext, SentMessage,
+    StorageOptimizeOptions, TransferOwnershipError,
+};
+pub use history::RequestRecord;
+pub use metrics::WorkerMetrics;
+#[cfg(any(test, feature = "testing"))]
+pub use mock::MockTdLibClient;
+pub use retry::RetryPolicy;
+pub use worker::{BindOptions, DropPolicy, Readiness, Worker, WorkerBuilder};
@@ -1,15 +1,23 @@
-use crate::types::TdType;
+use crate::{tdjson::ClientId, types::TdType};
 use futures::channel::oneshot;
 use std::collections::HashMap;
 #[doc(hidden)]
 use std::sync::RwLock;
+use std::time::{Duration, Instant};
 
 lazy_static::lazy_static! {
     pub(super) static ref OBSERVER: Observer = Observer::new();
 }
 
+// Request waiting for the response
+struct Subscription {
+    sender: oneshot::Sender<TdType>,
+    // client which sent the request and when it was sent
+    sent: Option<(ClientId, Instant)>,
+}
+
 pub(super) struct Observer {
-    channels: RwLock<HashMap<String, oneshot::Sender<TdType>>>,
+    channels: RwLock<HashMap<String, Subscription>>,
 }
 
 impl Observer {
@@ -32,9 +40,9 @@ impl Observer {
                         log::trace!("no subscribers for {}", extra);
                         Some(payload)
                     }
-                    Some(sender) => {
+                    Some(subscription) => {
                         log::trace!("signal send for {}", extra);
-                        if let Err(t) = sender.send(payload) {
+                        if let Err(t) = subscription.sender.send(payload) {
                             log::warn!("request already closed, received update: {:?}", t)
                         };
                         None
@@ -48,7 +56,7 @@ impl Observer {
         let (sender, receiver) = oneshot::channel::<TdType>();
         match self.channels.write() {
             Ok(mut map) => {
-                map.insert(extra.to_string(), sender);
+                map.insert(extra.to_string(), Subscription { sender, sent: None });
                 log::trace!("subscribed for {}", extra);
             }
             _ => {
@@ -58,6 +66,57 @@ impl Observer {
         receiver
     }
 
+    // Marks subscribed request as sent with `sent_extra`, which differs from subscribed one if `@extra` replaced by client's generator.
+    // Returns false and drops the subscription if another request already waits for the response with `sent_extra`
+    pub fn sent(&self, extra: &str, sent_extra: &str, client_id: ClientId) -> bool {
+        if let Ok(mut map) = self.channels.write() {
+            if sent_extra != extra && map.contains_key(sent_extra) {
+                log::error!(
+                    "request with @extra {} already waits for the response",
+                    sent_extra
+                );
+                map.remove(extra);
+                return false;
+            }
+            if let Some(mut subscription) = map.remove(extra) {
+                subscription.sent = Some((client_id, Instant::now()));
+                map.insert(sent_extra.to_string(), subscription);
+            }
+        };
+        true
+    }
+
+    // Returns client which sent the request waiting for the response and time since it was sent
+    pub fn elapsed(&self, extra: &str) -> Option<(ClientId, Duration)> {
+        self.channels
+            .read()
+            .ok()?
+            .get(extra)?
+            .sent
+            .map(|(client_id, sent_at)| (client_id, sent_at.elapsed()))
+    }
+
+    // Removes requests which receivers are dropped, e.g. because the response wasn't received in time
+    pub fn unsubscribe_closed(&self) {
+        if let Ok(mut map) = self.channels.write() {
+            map.retain(|_, subscription| !subscription.sender.is_canceled());
+        };
+    }
+
+    // Returns number of requests sent by clients matching `is_client` and waiting for the response
+    pub fn pending_count<F: Fn(ClientId) -> bool>(&self, is_client: F) -> usize {
+        self.channels
+            .read()
+            .map(|map| {
+                map.values()
+                    .filter(|subscription| {
+                        matches!(subscription.sent, Some((client_id, _)) if is_client(client_id))
+                    })
+                    .count()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn unsubscribe(&self, extra: &str) {
         if let Ok(mut map) = self.channels.write() {
             log::trace!("remove {} subscription", &extra);
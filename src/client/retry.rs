@@ -0,0 +1,98 @@
+use crate::errors::RTDError;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Determines which errors are worth retrying, see [send_with_retry](crate::client::client::Client::send_with_retry).
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    is_retryable: Arc<dyn Fn(&RTDError) -> bool + Send + Sync>,
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Up to 3 attempts, starting with 1 second delay; [flood wait](crate::errors::RTDError::FloodWait), [request timeout](crate::errors::RTDError::RequestTimeout)
+    /// and TDLib internal (5xx) errors are retried.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            is_retryable: Arc::new(|err| match err {
+                RTDError::FloodWait { .. } | RTDError::RequestTimeout => true,
+                RTDError::TDLibError(err) => err.code() >= 500,
+                _ => false,
+            }),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Specifies how many times request is sent at most, including the first attempt.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Specifies delay before the second attempt, doubled for each next one.
+    /// [Flood wait](crate::errors::RTDError::FloodWait) errors use delay requested by Telegram instead.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Specifies which errors are retried, others are returned immediately.
+    pub fn with_retryable<F>(mut self, is_retryable: F) -> Self
+    where
+        F: Fn(&RTDError) -> bool + Send + Sync + 'static,
+    {
+        self.is_retryable = Arc::new(is_retryable);
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn is_retryable(&self, err: &RTDError) -> bool {
+        (self.is_retryable)(err)
+    }
+
+    // Returns delay before the next attempt, `attempt` is the number of failed attempts so far
+    pub(crate) fn delay(&self, attempt: u32, err: &RTDError) -> Duration {
+        match err {
+            RTDError::FloodWait { retry_after } => Duration::from_secs(*retry_after),
+            _ => self
+                .base_delay
+                .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::RetryPolicy;
+    use crate::errors::RTDError;
+    use std::time::Duration;
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy::default().with_base_delay(Duration::from_millis(100));
+        let err = RTDError::Internal("test");
+        assert_eq!(policy.delay(1, &err), Duration::from_millis(100));
+        assert_eq!(policy.delay(3, &err), Duration::from_millis(400));
+
+        let flood_wait = RTDError::FloodWait { retry_after: 5 };
+        assert_eq!(policy.delay(1, &flood_wait), Duration::from_secs(5));
+        assert!(policy.is_retryable(&flood_wait));
+        assert!(!policy.is_retryable(&err));
+    }
+}
@@ -0,0 +1,186 @@
+use super::{
+    history::RequestHistory, metrics::Metrics, observer::OBSERVER, tdlib_client::TdLibClient,
+};
+use crate::{
+    errors::{flood_wait_retry_after, RTDError, RTDResult},
+    tdjson::ClientId,
+    types::{ExtraGenerator, RFunction, RObject, TdType},
+};
+use futures::channel::oneshot;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const NO_EXTRA: RTDError =
+    RTDError::Internal("invalid tdlib response type, not have `extra` field");
+const DUPLICATED_EXTRA: RTDError =
+    RTDError::BadRequest("generated `@extra` is used by another request waiting for the response");
+
+// Generator of requests `@extra`, wrapped to keep Client debuggable.
+#[derive(Clone)]
+pub(crate) struct ExtraGeneratorFn(pub Arc<dyn ExtraGenerator>);
+
+impl std::fmt::Debug for ExtraGeneratorFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ExtraGeneratorFn")
+    }
+}
+
+// Request sent with `@extra` produced by client's generator instead of the one set by request builder
+#[derive(Debug)]
+struct WithExtra<Fnc> {
+    function: Fnc,
+    extra: String,
+}
+
+impl<Fnc: RFunction> RObject for WithExtra<Fnc> {
+    fn extra(&self) -> Option<&str> {
+        Some(&self.extra)
+    }
+
+    fn client_id(&self) -> Option<i32> {
+        self.function.client_id()
+    }
+}
+
+impl<Fnc: RFunction> serde::Serialize for WithExtra<Fnc> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = serde_json::to_value(&self.function).map_err(serde::ser::Error::custom)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "@extra".to_string(),
+                serde_json::Value::String(self.extra.clone()),
+            );
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<Fnc: RFunction> RFunction for WithExtra<Fnc> {}
+
+// Sends requests of the client to TDLib. Request methods subscribe for the response in the observer and call `send`,
+// so everything done with outgoing requests (history, metrics, timeouts, `@extra` generation) lives here.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestSender<S> {
+    tdlib_client: S,
+    request_history: Option<Arc<RequestHistory>>,
+    metrics: Option<Metrics>,
+    shutdown_flag: Option<Arc<AtomicBool>>,
+    request_timeout: Option<Duration>,
+    extra_generator: Option<ExtraGeneratorFn>,
+}
+
+impl<S: TdLibClient> RequestSender<S> {
+    pub fn new(tdlib_client: S) -> Self {
+        Self {
+            tdlib_client,
+            request_history: None,
+            metrics: None,
+            shutdown_flag: None,
+            request_timeout: None,
+            extra_generator: None,
+        }
+    }
+
+    pub fn tdlib_client(&self) -> &S {
+        &self.tdlib_client
+    }
+
+    pub fn set_request_history(&mut self, request_history: Option<Arc<RequestHistory>>) {
+        self.request_history = request_history;
+    }
+
+    pub fn set_metrics(&mut self, metrics: Option<Metrics>) {
+        self.metrics = metrics;
+    }
+
+    pub fn set_shutdown_flag(&mut self, shutdown_flag: Arc<AtomicBool>) {
+        self.shutdown_flag = Some(shutdown_flag);
+    }
+
+    pub fn set_request_timeout(&mut self, request_timeout: Option<Duration>) {
+        self.request_timeout = request_timeout;
+    }
+
+    pub fn set_extra_generator(&mut self, extra_generator: Option<ExtraGeneratorFn>) {
+        self.extra_generator = extra_generator;
+    }
+
+    // Sends request, the caller must be subscribed for the response with request's `@extra`
+    pub fn send<Fnc: RFunction>(&self, client_id: ClientId, function: Fnc) -> RTDResult<()> {
+        let extra = function.extra().ok_or(NO_EXTRA)?.to_string();
+        let result = match &self.extra_generator {
+            None => self.send_with_extra(client_id, &extra, function),
+            Some(extra_generator) => {
+                let function = WithExtra {
+                    extra: extra_generator.0.generate(),
+                    function,
+                };
+                self.send_with_extra(client_id, &extra, function)
+            }
+        };
+        if let Err(err) = &result {
+            OBSERVER.unsubscribe(&extra);
+            if let Some(metrics) = &self.metrics {
+                metrics.0.on_error(err);
+            }
+        }
+        result
+    }
+
+    fn send_with_extra<Fnc: RFunction>(
+        &self,
+        client_id: ClientId,
+        subscribed_extra: &str,
+        function: Fnc,
+    ) -> RTDResult<()> {
+        if let Some(shutdown_flag) = &self.shutdown_flag {
+            if shutdown_flag.load(Ordering::Acquire) {
+                return Err(RTDError::BadRequest("worker is shutting down"));
+            }
+        }
+        let extra = function.extra().ok_or(NO_EXTRA)?.to_string();
+        if let Some(history) = &self.request_history {
+            history.record_request(client_id, &extra, function.to_json()?);
+        }
+        if !OBSERVER.sent(subscribed_extra, &extra, client_id) {
+            return Err(DUPLICATED_EXTRA);
+        }
+        if let Err(err) = self.tdlib_client.send(client_id, function) {
+            OBSERVER.unsubscribe(&extra);
+            return Err(err);
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.0.on_request_sent(client_id);
+        }
+        Ok(())
+    }
+
+    // Waits for the response to the sent request, RTDError::RequestTimeout returned if it isn't received within request timeout.
+    // TDLib flood wait errors are returned as RTDError::FloodWait, so every request method reports them the same way
+    pub async fn receive(
+        &self,
+        signal: oneshot::Receiver<TdType>,
+    ) -> RTDResult<Result<TdType, oneshot::Canceled>> {
+        let received = match self.request_timeout {
+            None => signal.await,
+            Some(request_timeout) => match tokio::time::timeout(request_timeout, signal).await {
+                Ok(received) => received,
+                Err(_) => {
+                    // the request is subscribed with `@extra` unknown here, so it's found by the dropped receiver
+                    OBSERVER.unsubscribe_closed();
+                    if let Some(metrics) = &self.metrics {
+                        metrics.0.on_error(&RTDError::RequestTimeout);
+                    }
+                    return Err(RTDError::RequestTimeout);
+                }
+            },
+        };
+        if let Ok(TdType::Error(err)) = &received {
+            if let Some(retry_after) = flood_wait_retry_after(err) {
+                return Err(RTDError::FloodWait { retry_after });
+            }
+        }
+        Ok(received)
+    }
+}
@@ -1,31 +1,40 @@
 //! Handlers for all incoming data
 use super::{
-    auth_handler::{AuthStateHandler, ConsoleAuthStateHandler},
-    client::{Client, ClientState},
+    auth_handler::{AuthStateHandler, ClientAuthData, ConsoleAuthStateHandler},
+    client::{Client, ClientState, CLOSED_RECEIVER_MESSAGE},
+    history::{RequestHistory, RequestRecord},
+    metrics::{self, Metrics, WorkerMetrics},
     observer::OBSERVER,
     tdlib_client::{TdJson, TdLibClient},
 };
 use crate::types::GetAuthorizationState;
 use crate::{
     errors::{RTDError, RTDResult},
-    tdjson::ClientId,
+    tdjson::{ClientId, LogLevel},
     types::{
-        from_json, AuthorizationState, CheckAuthenticationCode, CheckAuthenticationPassword,
-        CheckDatabaseEncryptionKey, GetApplicationConfig, RObject, RegisterUser,
-        SetAuthenticationPhoneNumber, SetTdlibParameters, TdType, Update, UpdateAuthorizationState,
+        from_json, AcceptTermsOfService, AuthorizationState, CheckAuthenticationBotToken,
+        CheckAuthenticationCode, CheckAuthenticationPassword, CheckDatabaseEncryptionKey, Close,
+        GetApplicationConfig, RObject, RegisterUser, RequestQrCodeAuthentication,
+        SetAuthenticationPhoneNumber, SetLogVerbosityLevel, SetTdlibParameters, TdType, Update,
+        UpdateAuthorizationState,
     },
 };
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
+use futures::{Future, Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::error::{SendTimeoutError, TrySendError};
 use tokio::sync::Mutex;
 use tokio::{
-    sync::{mpsc, RwLock},
+    sync::{mpsc, watch, RwLock},
     task::JoinHandle,
     time,
 };
 
+/// Size of channel used to deliver updates to [handlers](crate::client::worker::Worker::spawn_handler).
+const HANDLER_CHANNEL_SIZE: usize = 100;
+
 #[derive(Debug)]
 pub struct WorkerBuilder<A, T>
 where
@@ -33,7 +42,19 @@ where
     T: TdLibClient + Send + Sync + Clone + 'static,
 {
     read_updates_timeout: f64,
+    stop_poll_timeout: f64,
     channels_send_timeout: f64,
+    auth_timeout: f64,
+    request_history_capacity: Option<usize>,
+    max_concurrent_downloads: Option<usize>,
+    auto_accept_tos: bool,
+    auto_reconnect: Option<(u32, f64)>,
+    shutdown_timeout: f64,
+    log_verbosity: Option<LogLevel>,
+    unknown_update_handler: Option<UnknownUpdateHandler>,
+    metrics: Option<Metrics>,
+    auth_channel_capacity: usize,
+    drop_policy: DropPolicy,
     auth_state_handler: A,
     tdlib_client: T,
 }
@@ -43,7 +64,19 @@ impl Default for WorkerBuilder<ConsoleAuthStateHandler, TdJson> {
     fn default() -> Self {
         Self {
             read_updates_timeout: 2.0,
+            stop_poll_timeout: 0.1,
             channels_send_timeout: 5.0,
+            auth_timeout: 30.0,
+            request_history_capacity: None,
+            max_concurrent_downloads: None,
+            auto_accept_tos: false,
+            auto_reconnect: None,
+            shutdown_timeout: 5.0,
+            log_verbosity: None,
+            unknown_update_handler: None,
+            metrics: None,
+            auth_channel_capacity: 20,
+            drop_policy: DropPolicy::DropNewest,
             auth_state_handler: ConsoleAuthStateHandler::new(),
             tdlib_client: TdJson::new(),
         }
@@ -61,11 +94,101 @@ where
         self
     }
 
+    /// Specifies how long the updates loop may receive nothing from TDLib before it logs that it's idle; the message is repeated after each such interval.
+    /// It doesn't affect how long a single TDLib `receive` call blocks, see [with_stop_poll_timeout](crate::client::worker::WorkerBuilder::with_stop_poll_timeout).
     pub fn with_read_updates_timeout(mut self, read_updates_timeout: f64) -> Self {
         self.read_updates_timeout = read_updates_timeout;
         self
     }
 
+    /// Specifies how often the updates loop checks whether the worker was [stopped](crate::client::worker::Worker::stop).
+    /// Each TDLib `receive` call blocks for this interval at most, so `stop` takes effect within it.
+    pub fn with_stop_poll_timeout(mut self, stop_poll_timeout: f64) -> Self {
+        self.stop_poll_timeout = stop_poll_timeout;
+        self
+    }
+
+    /// Specifies how long [bind_client](crate::client::worker::Worker::bind_client) waits for the first TDLib response.
+    /// If TDLib does not respond in time, client is closed and [RTDError::AuthTimeout](crate::errors::RTDError::AuthTimeout) returned.
+    pub fn with_auth_timeout(mut self, auth_timeout: f64) -> Self {
+        self.auth_timeout = auth_timeout;
+        self
+    }
+
+    /// Enables in-memory history of the last `capacity` requests sent by bound clients, with their responses.
+    /// Useful for debugging, see [request_history](crate::client::worker::Worker::request_history).
+    pub fn with_request_history(mut self, capacity: usize) -> Self {
+        self.request_history_capacity = Some(capacity);
+        self
+    }
+
+    /// Limits number of files each bound client downloads simultaneously with [download_file_to_completion](crate::client::client::Client::download_file_to_completion).
+    /// Downloads above the limit wait until one of the running downloads completes.
+    pub fn with_max_concurrent_downloads(mut self, max_concurrent_downloads: usize) -> Self {
+        self.max_concurrent_downloads = Some(max_concurrent_downloads);
+        self
+    }
+
+    /// If enabled, new terms of service received with [UpdateTermsOfService](crate::types::UpdateTermsOfService) are accepted automatically.
+    /// Otherwise you have to call [accept_terms_of_service](crate::client::client::Client::accept_terms_of_service) yourself, as Telegram may stop sending updates until terms are accepted.
+    pub fn with_auto_accept_tos(mut self, auto_accept_tos: bool) -> Self {
+        self.auto_accept_tos = auto_accept_tos;
+        self
+    }
+
+    /// Enables reconnection of clients which failed authorization flow because TDLib stopped responding or closed the client:
+    /// worker closes TDLib client, creates new one and runs authorization flow again, with the same [handler](crate::client::client::AuthStateHandler) and [TdlibParameters](crate::types::TdlibParameters).
+    /// Errors of the handler and requests rejected by TDLib, e.g. wrong code or password, are sent to [client state channel](crate::client::client::ClientBuilder::with_auth_state_channel) without reconnection.
+    /// Attempts are delayed by `backoff` seconds, doubled after each attempt, and stop after `max_retries` consecutive failures;
+    /// only the final failure is sent to [client state channel](crate::client::client::ClientBuilder::with_auth_state_channel).
+    pub fn with_auto_reconnect(mut self, max_retries: u32, backoff: f64) -> Self {
+        self.auto_reconnect = Some((max_retries, backoff));
+        self
+    }
+
+    /// Specifies how long [shutdown](crate::client::worker::Worker::shutdown) waits for responses to already sent requests.
+    pub fn with_shutdown_timeout(mut self, shutdown_timeout: f64) -> Self {
+        self.shutdown_timeout = shutdown_timeout;
+        self
+    }
+
+    /// Sets verbosity level of TDLib internal logging when the worker is built, see [set_log_verbosity_level](crate::tdjson::set_log_verbosity_level).
+    /// Level is global for all TDLib clients of the process.
+    pub fn with_log_verbosity(mut self, log_verbosity: LogLevel) -> Self {
+        self.log_verbosity = Some(log_verbosity);
+        self
+    }
+
+    /// Specifies callback receiving raw JSON which can't be deserialized, e.g. updates introduced in newer TDLib versions.
+    /// Such data is logged and skipped anyway, updates loop keeps running.
+    pub fn with_unknown_update_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.unknown_update_handler = Some(UnknownUpdateHandler(Arc::new(handler)));
+        self
+    }
+
+    /// Specifies capacity of the channel delivering authorization state updates of all bound clients to the authorization task, 20 by default.
+    /// Increase it if many clients authorize simultaneously and `channels_send_timeout` is hit; each slot keeps one pending update in memory.
+    pub fn with_auth_channel_capacity(mut self, auth_channel_capacity: usize) -> Self {
+        self.auth_channel_capacity = auth_channel_capacity;
+        self
+    }
+
+    /// Specifies what happens with updates which can't be sent to client's [updates sender](crate::client::client::ClientBuilder::with_updates_sender) within `channels_send_timeout`,
+    /// see [DropPolicy](crate::client::worker::DropPolicy). Other clients are served regardless of the policy.
+    pub fn with_drop_policy(mut self, drop_policy: DropPolicy) -> Self {
+        self.drop_policy = drop_policy;
+        self
+    }
+
+    /// Specifies [hooks](crate::client::WorkerMetrics) called on received updates and on requests of bound clients, to collect metrics.
+    pub fn with_metrics<M: WorkerMetrics + 'static>(mut self, metrics: M) -> Self {
+        self.metrics = Some(Metrics(Arc::new(metrics)));
+        self
+    }
+
     /// [AuthStateHandler](crate::client::client::AuthStateHandler) allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
     /// See [AuthorizationState](crate::types::AuthorizationState).
     pub fn with_auth_state_handler<N>(self, auth_state_handler: N) -> WorkerBuilder<N, T>
@@ -75,7 +198,19 @@ where
         WorkerBuilder {
             auth_state_handler,
             read_updates_timeout: self.read_updates_timeout,
+            stop_poll_timeout: self.stop_poll_timeout,
             channels_send_timeout: self.channels_send_timeout,
+            auth_timeout: self.auth_timeout,
+            request_history_capacity: self.request_history_capacity,
+            max_concurrent_downloads: self.max_concurrent_downloads,
+            auto_accept_tos: self.auto_accept_tos,
+            auto_reconnect: self.auto_reconnect,
+            shutdown_timeout: self.shutdown_timeout,
+            log_verbosity: self.log_verbosity,
+            unknown_update_handler: self.unknown_update_handler,
+            metrics: self.metrics,
+            auth_channel_capacity: self.auth_channel_capacity,
+            drop_policy: self.drop_policy,
             tdlib_client: self.tdlib_client,
         }
     }
@@ -89,26 +224,101 @@ where
             tdlib_client,
             auth_state_handler: self.auth_state_handler,
             read_updates_timeout: self.read_updates_timeout,
+            stop_poll_timeout: self.stop_poll_timeout,
             channels_send_timeout: self.channels_send_timeout,
+            auth_timeout: self.auth_timeout,
+            request_history_capacity: self.request_history_capacity,
+            max_concurrent_downloads: self.max_concurrent_downloads,
+            auto_accept_tos: self.auto_accept_tos,
+            auto_reconnect: self.auto_reconnect,
+            shutdown_timeout: self.shutdown_timeout,
+            log_verbosity: self.log_verbosity,
+            unknown_update_handler: self.unknown_update_handler,
+            metrics: self.metrics,
+            auth_channel_capacity: self.auth_channel_capacity,
+            drop_policy: self.drop_policy,
         }
     }
 
     pub fn build(self) -> RTDResult<Worker<A, T>> {
-        let worker = Worker::new(
-            self.auth_state_handler,
-            self.read_updates_timeout,
-            self.channels_send_timeout,
-            self.tdlib_client,
-        );
+        if let Some(log_verbosity) = self.log_verbosity {
+            self.tdlib_client.execute(
+                SetLogVerbosityLevel::builder()
+                    .new_verbosity_level(log_verbosity.into())
+                    .build(),
+            )?;
+        }
+        let worker = Worker::new(self);
         Ok(worker)
     }
 }
 
+// Callback for data which can't be deserialized, wrapped to keep WorkerBuilder debuggable.
+#[derive(Clone)]
+struct UnknownUpdateHandler(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl std::fmt::Debug for UnknownUpdateHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "UnknownUpdateHandler")
+    }
+}
+
+// Span for data received from TDLib, `client_id` is known only for updates
+#[cfg(feature = "tracing")]
+fn received_span(data: &TdType) -> tracing::Span {
+    let client_id = match data {
+        TdType::Update(update) => update.client_id(),
+        _ => None,
+    };
+    tracing::debug_span!("received", client_id = ?client_id, extra = ?data.extra())
+}
+
 pub(crate) type StateMessage = RTDResult<ClientState, (RTDError, UpdateAuthorizationState)>;
 
+// Handler bound with a single client, wrapped to keep ClientContext debuggable.
+#[derive(Clone)]
+struct ClientAuthStateHandler(Arc<dyn AuthStateHandler + Send + Sync>);
+
+impl std::fmt::Debug for ClientAuthStateHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ClientAuthStateHandler")
+    }
+}
+
+/// Options of the client binding, see [bind_client_with_options](crate::client::worker::Worker::bind_client_with_options).
+#[derive(Debug, Clone, Default)]
+pub struct BindOptions {
+    label: Option<String>,
+    auth_state_handler: Option<ClientAuthStateHandler>,
+}
+
+impl BindOptions {
+    /// Registers client under specified label, so it can be found later with [client_by_label](crate::client::worker::Worker::client_by_label).
+    /// Labels are unique within the worker.
+    pub fn with_label<L: Into<String>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Authorization states of the client are passed to specified handler instead of the one [set to the worker](crate::client::worker::WorkerBuilder::with_auth_state_handler),
+    /// so each client can use its own credentials.
+    pub fn with_auth_state_handler<H>(mut self, auth_state_handler: H) -> Self
+    where
+        H: AuthStateHandler + Send + Sync + 'static,
+    {
+        self.auth_state_handler = Some(ClientAuthStateHandler(Arc::new(auth_state_handler)));
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 struct ClientContext<S: TdLibClient + Clone> {
     client: Client<S>,
+    label: Option<String>,
+    auth_state_handler: Option<ClientAuthStateHandler>,
+    state_sender: Arc<watch::Sender<ClientState>>,
+    reconnect_attempts: Arc<AtomicU32>,
+    updates_delivery: Arc<UpdatesDelivery>,
     private_state_message_sender: mpsc::Sender<ClientState>,
     private_state_message_receiver: Arc<Mutex<mpsc::Receiver<ClientState>>>,
     pub_state_message_sender: Option<mpsc::Sender<StateMessage>>,
@@ -122,6 +332,28 @@ where
     pub fn client(&self) -> &Client<S> {
         &self.client
     }
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+    // Returns handler bound with the client, falling back to the worker's one
+    pub fn auth_state_handler<'a>(
+        &'a self,
+        default: &'a (dyn AuthStateHandler + Send + Sync),
+    ) -> &'a (dyn AuthStateHandler + Send + Sync) {
+        match &self.auth_state_handler {
+            Some(handler) => handler.0.as_ref(),
+            None => default,
+        }
+    }
+    pub fn state_sender(&self) -> &watch::Sender<ClientState> {
+        &self.state_sender
+    }
+    pub fn reconnect_attempts(&self) -> &AtomicU32 {
+        &self.reconnect_attempts
+    }
+    pub fn updates_delivery(&self) -> &UpdatesDelivery {
+        &self.updates_delivery
+    }
     pub fn private_state_message_receiver(&self) -> &Arc<Mutex<mpsc::Receiver<ClientState>>> {
         &self.private_state_message_receiver
     }
@@ -138,6 +370,193 @@ where
 
 type ClientsMap<S> = HashMap<ClientId, ClientContext<S>>;
 
+/// Determines what happens with an update which can't be sent to client's [updates sender](crate::client::client::ClientBuilder::with_updates_sender) within `channels_send_timeout`,
+/// or to [update subscriber](crate::client::client::Client::add_update_subscriber) which channel is full, see [with_drop_policy](crate::client::worker::WorkerBuilder::with_drop_policy). Dropped updates are counted, see [dropped_updates](crate::client::worker::Worker::dropped_updates).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropPolicy {
+    /// Undelivered updates are queued and sent when the receiver catches up, before newer ones; when the queue exceeds capacity of the channel, the oldest queued update is dropped.
+    /// Worker doesn't wait for the receiver while the queue is not empty.
+    DropOldest,
+    /// Update is dropped. Default.
+    DropNewest,
+    /// Update is dropped and the worker stops sending updates to the client, all later updates are dropped too.
+    Error,
+}
+
+// State of updates delivery to the client's updates sender or update subscriber
+#[derive(Debug)]
+pub(crate) struct UpdatesDelivery {
+    // shared by all deliveries of the client
+    dropped: Arc<AtomicU64>,
+    stopped: AtomicBool,
+    queue: std::sync::Mutex<VecDeque<Box<Update>>>,
+}
+
+impl UpdatesDelivery {
+    pub(crate) fn new(dropped: Arc<AtomicU64>) -> Self {
+        Self {
+            dropped,
+            stopped: AtomicBool::new(false),
+            queue: std::sync::Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn drop_updates(&self, count: usize) {
+        self.dropped.fetch_add(count as u64, Ordering::AcqRel);
+    }
+
+    // Sends queued updates while there is free space in the channel; returns false if some of them left in the queue
+    fn flush(&self, sender: &mpsc::Sender<Box<Update>>) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        while let Some(update) = queue.pop_front() {
+            match sender.try_send(update) {
+                Ok(_) => {}
+                Err(TrySendError::Full(update)) => {
+                    queue.push_front(update);
+                    return false;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    self.drop_updates(queue.len() + 1);
+                    queue.clear();
+                }
+            }
+        }
+        true
+    }
+
+    // Queues the update, dropping the oldest queued one if the queue is full
+    fn enqueue(&self, update: Box<Update>, capacity: usize) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.push_back(update);
+        if queue.len() > capacity {
+            queue.pop_front();
+            self.drop_updates(1);
+            log::warn!("updates receiver is too slow, oldest queued update dropped");
+        }
+    }
+}
+
+// Receiver of all updates of the client, see Client::add_update_subscriber
+#[derive(Debug, Clone)]
+pub(crate) struct UpdateSubscriber {
+    pub sender: mpsc::Sender<Box<Update>>,
+    pub delivery: Arc<UpdatesDelivery>,
+}
+
+// Sends update to the update subscriber without waiting, so a slow subscriber doesn't hold up other clients; applies `drop_policy` if the subscriber doesn't keep up
+fn deliver_to_subscriber(
+    subscriber: &UpdateSubscriber,
+    update: Box<Update>,
+    drop_policy: DropPolicy,
+) {
+    let (sender, delivery) = (&subscriber.sender, &subscriber.delivery);
+    if delivery.stopped.load(Ordering::Acquire) {
+        delivery.drop_updates(1);
+        return;
+    }
+    if drop_policy == DropPolicy::DropOldest && !delivery.flush(sender) {
+        delivery.enqueue(update, sender.max_capacity());
+        return;
+    }
+    match sender.try_send(update) {
+        Ok(_) => log::trace!("update sent to subscriber"),
+        Err(TrySendError::Full(update)) => match drop_policy {
+            DropPolicy::DropOldest => {
+                log::warn!("update subscriber is too slow, update queued");
+                delivery.enqueue(update, sender.max_capacity());
+            }
+            DropPolicy::DropNewest => {
+                log::warn!("update subscriber is too slow, update dropped");
+                delivery.drop_updates(1);
+            }
+            DropPolicy::Error => {
+                log::error!("update subscriber is too slow, stop sending updates to it");
+                delivery.stopped.store(true, Ordering::Release);
+                delivery.drop_updates(1);
+            }
+        },
+        // subscriber is removed with the next update, see Client::update_subscribers
+        Err(TrySendError::Closed(_)) => log::trace!("update subscriber closed"),
+    }
+}
+
+// Reports response to the request sent by a client
+fn report_response(metrics: &Metrics, client_id: ClientId, response: &TdType, elapsed: Duration) {
+    metrics.0.on_response_received(client_id, elapsed);
+    if let TdType::Error(err) = response {
+        metrics.0.on_error(&RTDError::TDLibError(err.clone()));
+    }
+}
+
+// Sends update to the client's updates sender, applying `drop_policy` if the receiver doesn't keep up
+async fn deliver_update(
+    sender: &mpsc::Sender<Box<Update>>,
+    delivery: &UpdatesDelivery,
+    update: Box<Update>,
+    drop_policy: DropPolicy,
+    send_timeout: Duration,
+) {
+    if delivery.stopped.load(Ordering::Acquire) {
+        delivery.drop_updates(1);
+        return;
+    }
+    if drop_policy == DropPolicy::DropOldest && !delivery.flush(sender) {
+        delivery.enqueue(update, sender.max_capacity());
+        return;
+    }
+    match sender.send_timeout(update, send_timeout).await {
+        Ok(_) => log::trace!("update sent"),
+        Err(SendTimeoutError::Timeout(update)) => match drop_policy {
+            DropPolicy::DropOldest => {
+                log::warn!("updates receiver is too slow, update queued");
+                delivery.enqueue(update, sender.max_capacity());
+            }
+            DropPolicy::DropNewest => {
+                log::warn!("updates receiver is too slow, update dropped");
+                delivery.drop_updates(1);
+            }
+            DropPolicy::Error => {
+                log::error!("updates receiver is too slow, stop sending updates to the client");
+                delivery.stopped.store(true, Ordering::Release);
+                delivery.drop_updates(1);
+            }
+        },
+        Err(SendTimeoutError::Closed(_)) => {
+            log::error!("can't send update: receiver closed");
+            delivery.drop_updates(1);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AutoReconnect {
+    max_retries: u32,
+    backoff: Duration,
+}
+
+/// Health of the worker, see [readiness](crate::client::worker::Worker::readiness).
+#[derive(Debug, Clone)]
+pub struct Readiness {
+    tasks_running: bool,
+    last_receive: Option<Instant>,
+    client_count: usize,
+}
+
+impl Readiness {
+    /// Determines that background tasks of the worker are running.
+    pub fn tasks_running(&self) -> bool {
+        self.tasks_running
+    }
+    /// Time when any data was received from TDLib last time. None if nothing received yet.
+    pub fn last_receive(&self) -> Option<Instant> {
+        self.last_receive
+    }
+    /// Number of clients bound with the worker.
+    pub fn client_count(&self) -> usize {
+        self.client_count
+    }
+}
+
 /// The main object in all interactions.
 /// You have to [start](crate::client::worker::Worker::start) worker and bind each client with worker using [auth_client](crate::client::worker::Worker::auth_client).
 #[derive(Debug, Clone)]
@@ -149,7 +568,20 @@ where
     run_flag: Arc<AtomicBool>,
     auth_state_handler: Arc<A>,
     read_updates_timeout: Duration,
+    stop_poll_timeout: Duration,
     channels_send_timeout: Duration,
+    auth_timeout: Duration,
+    request_history: Option<Arc<RequestHistory>>,
+    max_concurrent_downloads: Option<usize>,
+    auto_accept_tos: bool,
+    auto_reconnect: Option<AutoReconnect>,
+    shutdown_timeout: Duration,
+    shutting_down: Arc<AtomicBool>,
+    unknown_update_handler: Option<UnknownUpdateHandler>,
+    metrics: Option<Metrics>,
+    auth_channel_capacity: usize,
+    drop_policy: DropPolicy,
+    last_receive: Arc<std::sync::Mutex<Option<Instant>>>,
     tdlib_client: S,
     clients: Arc<RwLock<ClientsMap<S>>>,
 }
@@ -178,7 +610,7 @@ where
                 panic!()
             }
             AuthorizationState::Closed(_) => Ok((ClientState::Closed, state)),
-            AuthorizationState::Closing(_) => Ok((ClientState::Closed, state)),
+            AuthorizationState::Closing(_) => Ok((ClientState::Closing, state)),
             AuthorizationState::LoggingOut(_) => Ok((ClientState::Closed, state)),
             AuthorizationState::Ready(_) => Ok((ClientState::Opened, state)),
             AuthorizationState::WaitCode(_) => Ok((ClientState::Authorizing, state)),
@@ -205,6 +637,33 @@ where
         Ok(())
     }
 
+    /// Closes single client and removes it from the worker, other bound clients keep working.
+    /// Method waits until TDLib reports [closed](crate::types::AuthorizationStateClosed) state, up to `auth_timeout`.
+    pub async fn close_client(&self, client_id: ClientId) -> RTDResult<()> {
+        let (client, state_receiver) = match self.clients.read().await.get(&client_id) {
+            None => return Err(RTDError::BadRequest("client not found")),
+            Some(ctx) => (
+                ctx.client().clone(),
+                ctx.private_state_message_receiver().clone(),
+            ),
+        };
+        client.stop().await?;
+        let wait_closed = async {
+            let mut receiver = state_receiver.lock().await;
+            loop {
+                match receiver.recv().await {
+                    None | Some(ClientState::Closed) => break,
+                    Some(_) => continue,
+                }
+            }
+        };
+        let result = time::timeout(self.auth_timeout, wait_closed)
+            .await
+            .map_err(|_| RTDError::Internal("client not closed in time"));
+        self.clients.write().await.remove(&client_id);
+        result
+    }
+
     /// Method waits for client state changes.
     /// If an error occured during authorization flow, you receive [AuthorizationState](crate::types::authorization_state::AuthorizationState) on which it happened.
     /// You have to setup [channel](tokio::sync::mpsc::channel) by call [Client::builder().with_auth_state_channel(...)](Client::builder().with_auth_state_channel(...))
@@ -238,15 +697,98 @@ where
         }
     }
 
+    /// Returns stream of state transitions of the client, starting with its current state.
+    /// Unlike [wait_client_state](crate::client::worker::Worker::wait_client_state), any number of streams can be used simultaneously;
+    /// slow consumer skips intermediate states and receives the latest one. Stream ends when the client removed from the worker.
+    pub async fn client_state_stream(
+        &self,
+        client_id: ClientId,
+    ) -> RTDResult<impl Stream<Item = ClientState>> {
+        let receiver = match self.clients.read().await.get(&client_id) {
+            None => return Err(RTDError::BadRequest("client not found")),
+            Some(ctx) => ctx.state_sender().subscribe(),
+        };
+        Ok(futures::stream::unfold(
+            (receiver, true),
+            |(mut receiver, first)| async move {
+                if !first && receiver.changed().await.is_err() {
+                    return None;
+                }
+                let state = receiver.borrow_and_update().clone();
+                Some((state, (receiver, false)))
+            },
+        ))
+    }
+
     /// Binds client with worker and runs authorization routines.
     /// Method returns error if worker is not running or client already bound
-    pub async fn bind_client(&mut self, mut client: Client<T>) -> RTDResult<Client<T>> {
+    pub async fn bind_client(&mut self, client: Client<T>) -> RTDResult<Client<T>> {
+        self.bind_client_with_options(client, BindOptions::default())
+            .await
+    }
+
+    /// Same as [bind_client](crate::client::worker::Worker::bind_client), but authorization states of the client are passed to specified `auth_state_handler`
+    /// instead of the one [set to the worker](crate::client::worker::WorkerBuilder::with_auth_state_handler), so each client can use its own credentials.
+    pub async fn bind_client_with_handler<H>(
+        &mut self,
+        client: Client<T>,
+        auth_state_handler: H,
+    ) -> RTDResult<Client<T>>
+    where
+        H: AuthStateHandler + Send + Sync + 'static,
+    {
+        let options = BindOptions::default().with_auth_state_handler(auth_state_handler);
+        self.bind_client_with_options(client, options).await
+    }
+
+    /// Same as [bind_client](crate::client::worker::Worker::bind_client), but also registers client under specified label,
+    /// so it can be found later with [client_by_label](crate::client::worker::Worker::client_by_label).
+    /// Method returns error if another client already bound with the same label.
+    pub async fn bind_client_with_label<L: Into<String>>(
+        &mut self,
+        client: Client<T>,
+        label: L,
+    ) -> RTDResult<Client<T>> {
+        self.bind_client_with_options(client, BindOptions::default().with_label(label))
+            .await
+    }
+
+    /// Returns client bound with specified label.
+    pub async fn client_by_label(&self, label: &str) -> Option<Client<T>> {
+        self.clients
+            .read()
+            .await
+            .values()
+            .find(|ctx| ctx.label() == Some(label))
+            .map(|ctx| ctx.client().clone())
+    }
+
+    /// Same as [bind_client](crate::client::worker::Worker::bind_client), but allows to specify label and authorization states handler of the client, see [BindOptions].
+    /// Method returns error if another client already bound with the same label.
+    pub async fn bind_client_with_options(
+        &mut self,
+        mut client: Client<T>,
+        options: BindOptions,
+    ) -> RTDResult<Client<T>> {
         if !self.is_running() {
             return Err(RTDError::BadRequest("worker not started yet"));
         };
+        // label is checked and the client is inserted under the same lock, so concurrent binds can't take the same label
+        let mut clients = self.clients.write().await;
+        if let Some(label) = &options.label {
+            if clients.values().any(|ctx| ctx.label() == Some(label)) {
+                return Err(RTDError::BadRequest(
+                    "client with the same label already bound",
+                ));
+            }
+        };
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("new client created: {}", client_id);
         client.set_client_id(client_id)?;
+        client.set_request_history(self.request_history.clone());
+        client.set_max_concurrent_downloads(self.max_concurrent_downloads);
+        client.set_shutdown_flag(self.shutting_down.clone());
+        client.set_metrics(self.metrics.clone());
 
         let (sx, rx) = match client.get_auth_state_channel_size() {
             None => (None, None),
@@ -259,24 +801,111 @@ where
         let (psx, prx) = mpsc::channel::<ClientState>(5);
         let ctx = ClientContext {
             client: client.clone(),
+            label: options.label,
+            auth_state_handler: options.auth_state_handler,
+            state_sender: Arc::new(watch::channel(ClientState::Authorizing).0),
+            reconnect_attempts: Arc::new(AtomicU32::new(0)),
+            updates_delivery: Arc::new(UpdatesDelivery::new(client.dropped_updates())),
             pub_state_message_sender: sx,
             pub_state_message_receiver: rx,
             private_state_message_receiver: Arc::new(Mutex::new(prx)),
             private_state_message_sender: psx,
         };
 
-        self.clients.write().await.insert(client_id, ctx);
+        clients.insert(client_id, ctx);
+        drop(clients);
         log::debug!("new client added");
 
         // We need to call any tdlib method to retrieve first response.
         // Otherwise client can't be authorized: no `UpdateAuthorizationState` send by TDLib.
-        first_internal_request(&client.get_tdlib_client(), client_id).await;
+        if let Err(err) =
+            first_internal_request(&client.get_tdlib_client(), client_id, self.auth_timeout).await
+        {
+            log::error!("no first response received for client {}", client_id);
+            self.clients.write().await.remove(&client_id);
+            if let Err(err) = client
+                .get_tdlib_client()
+                .send(client_id, Close::builder().build())
+            {
+                log::error!("can't close client: {}", err)
+            };
+            return Err(err);
+        };
 
         log::trace!("received first internal response");
 
         Ok(client)
     }
 
+    /// Spawns task which calls `handler` for every update of the client, waiting for each call to complete before the next one.
+    /// Task stops when the worker stops sending updates, e.g. after client closed. Returns error if client is not bound with the worker.
+    pub async fn spawn_handler<F, Fut>(
+        &self,
+        client: &Client<T>,
+        handler: F,
+    ) -> RTDResult<JoinHandle<()>>
+    where
+        F: Fn(Update) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_handler_with_concurrency(client, 1, handler)
+            .await
+    }
+
+    /// Same as [spawn_handler](crate::client::worker::Worker::spawn_handler), but allows up to `concurrency` handler calls to run simultaneously.
+    /// Zero `concurrency` means no limit.
+    pub async fn spawn_handler_with_concurrency<F, Fut>(
+        &self,
+        client: &Client<T>,
+        concurrency: usize,
+        handler: F,
+    ) -> RTDResult<JoinHandle<()>>
+    where
+        F: Fn(Update) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let receiver = match self.clients.read().await.get(&client.get_client_id()?) {
+            None => return Err(RTDError::BadRequest("client not found")),
+            Some(ctx) => ctx.client().add_update_subscriber(HANDLER_CHANNEL_SIZE),
+        };
+        let updates = futures::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|update| (update, receiver))
+        });
+        Ok(tokio::spawn(async move {
+            updates
+                .for_each_concurrent(concurrency, |update| handler(*update))
+                .await
+        }))
+    }
+
+    /// Returns the last requests sent by bound clients, oldest first.
+    /// Empty unless enabled with [with_request_history](crate::client::worker::WorkerBuilder::with_request_history).
+    pub fn request_history(&self) -> Vec<RequestRecord> {
+        match &self.request_history {
+            None => Vec::new(),
+            Some(history) => history.records(),
+        }
+    }
+
+    /// Reports health of the worker itself, regardless of authorization state of bound clients.
+    pub async fn readiness(&self) -> Readiness {
+        let client_count = self.clients.read().await.len();
+        Readiness {
+            tasks_running: self.is_running(),
+            last_receive: *self.last_receive.lock().unwrap(),
+            client_count,
+        }
+    }
+
+    /// Returns number of updates of the client which weren't delivered to its [updates sender](crate::client::client::ClientBuilder::with_updates_sender)
+    /// or [update subscribers](crate::client::client::Client::add_update_subscriber), see [DropPolicy](crate::client::worker::DropPolicy).
+    pub async fn dropped_updates(&self, client_id: ClientId) -> RTDResult<u64> {
+        match self.clients.read().await.get(&client_id) {
+            None => Err(RTDError::BadRequest("client not found")),
+            Some(ctx) => Ok(ctx.updates_delivery().dropped.load(Ordering::Acquire)),
+        }
+    }
+
     /// Determines that the worker is running.
     pub fn is_running(&self) -> bool {
         self.run_flag.load(Ordering::Acquire)
@@ -288,10 +917,19 @@ where
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("new client created: {}", client_id);
         client.set_client_id(client_id).unwrap();
+        client.set_request_history(self.request_history.clone());
+        client.set_max_concurrent_downloads(self.max_concurrent_downloads);
+        client.set_shutdown_flag(self.shutting_down.clone());
+        client.set_metrics(self.metrics.clone());
 
         let (psx, prx) = mpsc::channel::<ClientState>(5);
         let ctx = ClientContext {
             client: client.clone(),
+            label: None,
+            auth_state_handler: None,
+            state_sender: Arc::new(watch::channel(ClientState::Authorizing).0),
+            reconnect_attempts: Arc::new(AtomicU32::new(0)),
+            updates_delivery: Arc::new(UpdatesDelivery::new(client.dropped_updates())),
             pub_state_message_sender: None,
             pub_state_message_receiver: None,
             private_state_message_receiver: Arc::new(Mutex::new(prx)),
@@ -303,21 +941,36 @@ where
     }
 
     // Client must be created only with builder
-    pub(crate) fn new(
-        auth_state_handler: A,
-        read_updates_timeout: f64,
-        channels_send_timeout: f64,
-        tdlib_client: T,
-    ) -> Self {
+    pub(crate) fn new(builder: WorkerBuilder<A, T>) -> Self {
         let run_flag = Arc::new(AtomicBool::new(false));
         let clients: ClientsMap<T> = HashMap::new();
 
         Self {
             run_flag,
-            tdlib_client,
-            read_updates_timeout: time::Duration::from_secs_f64(read_updates_timeout),
-            channels_send_timeout: time::Duration::from_secs_f64(channels_send_timeout),
-            auth_state_handler: Arc::new(auth_state_handler),
+            tdlib_client: builder.tdlib_client,
+            read_updates_timeout: time::Duration::from_secs_f64(builder.read_updates_timeout),
+            stop_poll_timeout: time::Duration::from_secs_f64(builder.stop_poll_timeout),
+            channels_send_timeout: time::Duration::from_secs_f64(builder.channels_send_timeout),
+            auth_timeout: time::Duration::from_secs_f64(builder.auth_timeout),
+            request_history: builder
+                .request_history_capacity
+                .map(|capacity| Arc::new(RequestHistory::new(capacity))),
+            max_concurrent_downloads: builder.max_concurrent_downloads,
+            auto_accept_tos: builder.auto_accept_tos,
+            auto_reconnect: builder
+                .auto_reconnect
+                .map(|(max_retries, backoff)| AutoReconnect {
+                    max_retries,
+                    backoff: time::Duration::from_secs_f64(backoff),
+                }),
+            shutdown_timeout: time::Duration::from_secs_f64(builder.shutdown_timeout),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            unknown_update_handler: builder.unknown_update_handler,
+            metrics: builder.metrics,
+            auth_channel_capacity: builder.auth_channel_capacity,
+            drop_policy: builder.drop_policy,
+            last_receive: Arc::new(std::sync::Mutex::new(None)),
+            auth_state_handler: Arc::new(builder.auth_state_handler),
             clients: Arc::new(RwLock::new(clients)),
         }
     }
@@ -325,7 +978,8 @@ where
     /// Starts interaction with TDLib.
     /// It returns [JoinHandle](tokio::task::JoinHandle) which allows you to handle worker state: if it yields - so worker is definitely stopped.
     pub fn start(&mut self) -> JoinHandle<()> {
-        let (auth_sx, auth_rx) = mpsc::channel::<UpdateAuthorizationState>(20);
+        let (auth_sx, auth_rx) =
+            mpsc::channel::<UpdateAuthorizationState>(self.auth_channel_capacity);
 
         self.run_flag.store(true, Ordering::Release);
         let updates_handle = self.init_updates_task(auth_sx);
@@ -348,20 +1002,83 @@ where
 
     /// Stops the client.
     /// You may want to await JoinHandle retrieved with `client.start().await` after calling `stop`.
+    /// Worker stops immediately: requests waiting for responses never complete and bound clients aren't closed, see [shutdown](crate::client::worker::Worker::shutdown).
     pub fn stop(&self) {
         self.run_flag.store(false, Ordering::Release);
     }
 
+    /// Stops the worker gracefully, unlike [stop](crate::client::worker::Worker::stop):
+    /// new requests of bound clients are rejected, already sent requests get responses, then all bound clients are closed and the worker stopped.
+    /// Both waits share [shutdown timeout](crate::client::worker::WorkerBuilder::with_shutdown_timeout).
+    pub async fn shutdown(self) {
+        self.shutting_down.store(true, Ordering::Release);
+        let deadline = time::Instant::now() + self.shutdown_timeout;
+        let pending_count = || async {
+            let clients = self.clients.read().await;
+            OBSERVER.pending_count(|client_id| clients.contains_key(&client_id))
+        };
+        let drained = time::timeout_at(deadline, async {
+            while self.is_running() && pending_count().await > 0 {
+                time::sleep(self.stop_poll_timeout).await;
+            }
+        })
+        .await;
+        if drained.is_err() {
+            log::warn!(
+                "{} requests not completed before shutdown",
+                pending_count().await
+            );
+        }
+
+        // clients are closed through the usual auth state handling, so their Closing and Closed states are delivered
+        let mut state_receivers = Vec::new();
+        for (client_id, ctx) in self.clients.read().await.iter() {
+            if let Err(err) = ctx
+                .client()
+                .get_tdlib_client()
+                .send(*client_id, Close::builder().build())
+            {
+                log::error!("can't close client {}: {}", client_id, err);
+                continue;
+            }
+            state_receivers.push(ctx.state_sender().subscribe());
+        }
+        let closed = time::timeout_at(deadline, async {
+            for mut receiver in state_receivers {
+                while *receiver.borrow_and_update() != ClientState::Closed {
+                    if receiver.changed().await.is_err() {
+                        break;
+                    }
+                }
+            }
+        })
+        .await;
+        if closed.is_err() {
+            log::warn!("not all clients closed before shutdown");
+        }
+        self.clients.write().await.clear();
+        self.stop();
+    }
+
     // It's the base routine: sends received updates to particular handlers: observer or auth_state handler
     fn init_updates_task(&self, auth_sx: mpsc::Sender<UpdateAuthorizationState>) -> JoinHandle<()> {
         let run_flag = self.run_flag.clone();
         let clients = self.clients.clone();
-        let recv_timeout = self.read_updates_timeout;
+        // receive with short timeout, so `stop` takes effect quickly; `read_updates_timeout` only limits how long the loop is silently idle
+        let recv_timeout = self.stop_poll_timeout;
+        let idle_timeout = self.read_updates_timeout;
         let send_timeout = self.channels_send_timeout;
         let tdlib_client = Arc::new(self.tdlib_client.clone());
+        let request_history = self.request_history.clone();
+        let last_receive = self.last_receive.clone();
+        let auto_accept_tos = self.auto_accept_tos;
+        let unknown_update_handler = self.unknown_update_handler.clone();
+        let metrics = self.metrics.clone();
+        let drop_policy = self.drop_policy;
 
         tokio::spawn(async move {
             let current = tokio::runtime::Handle::try_current().unwrap();
+            let mut idle_since = Instant::now();
             while run_flag.load(Ordering::Acquire) {
                 let cl = tdlib_client.clone();
                 if let Some(json) = current
@@ -370,49 +1087,116 @@ where
                     .unwrap()
                 {
                     log::trace!("received json from tdlib: {}", json);
+                    idle_since = Instant::now();
+                    *last_receive.lock().unwrap() = Some(idle_since);
                     match from_json::<TdType>(&json) {
-                        Err(e) => log::error!("can't deserialize tdlib data: {}", e),
+                        Err(e) => {
+                            log::warn!("can't deserialize tdlib data: {}, skipping: {}", e, json);
+                            if let Some(metrics) = &metrics {
+                                metrics.0.on_error(&e);
+                            }
+                            if let Some(handler) = &unknown_update_handler {
+                                (handler.0)(&json);
+                            }
+                        }
                         Ok(t) => {
-                            if let Some(TdType::Update(update)) = OBSERVER.notify(t) {
-                                if let Update::AuthorizationState(auth_state) = update {
-                                    log::trace!("auth state send: {:?}", auth_state);
-                                    match auth_sx.send_timeout(auth_state, send_timeout).await {
-                                        Ok(_) => {
-                                            log::trace!("auth state sent");
-                                        }
-                                        Err(err) => {
-                                            log::error!("can't send auth state update: {}", err)
-                                        }
-                                    };
-                                } else if let Some(client_id) = update.client_id() {
-                                    match clients.read().await.get(&client_id) {
-                                        None => {
-                                            log::warn!(
-                                                "found updates for unavailable client ({})",
-                                                client_id
-                                            )
+                            #[cfg(feature = "tracing")]
+                            let span = received_span(&t);
+                            let handle_received = async {
+                                if let (Some(history), Some(extra)) = (&request_history, t.extra())
+                                {
+                                    history.record_response(extra, &json);
+                                }
+                                if let (Some(metrics), Some(extra)) = (&metrics, t.extra()) {
+                                    if let Some((client_id, elapsed)) = OBSERVER.elapsed(extra) {
+                                        report_response(metrics, client_id, &t, elapsed);
+                                    }
+                                }
+                                if let Some(TdType::Update(update)) = OBSERVER.notify(t) {
+                                    if let Some(metrics) = &metrics {
+                                        if let Some(td_type) = metrics::td_type(&json) {
+                                            metrics.0.on_update_received(td_type);
                                         }
-                                        Some(ctx) => {
-                                            if let Some(sender) = ctx.client().updates_sender() {
-                                                log::trace!("sending update to client");
-                                                match sender
-                                                    .send_timeout(Box::new(update), send_timeout)
-                                                    .await
-                                                {
-                                                    Ok(_) => {
-                                                        log::trace!("update sent");
-                                                    }
-                                                    Err(err) => {
-                                                        log::error!("can't send update: {}", err)
+                                    }
+                                    if let Update::AuthorizationState(auth_state) = update {
+                                        log::trace!("auth state send: {:?}", auth_state);
+                                        match auth_sx.send_timeout(auth_state, send_timeout).await {
+                                            Ok(_) => {
+                                                log::trace!("auth state sent");
+                                            }
+                                            Err(err) => {
+                                                log::error!("can't send auth state update: {}", err)
+                                            }
+                                        };
+                                    } else if let Some(client_id) = update.client_id() {
+                                        match clients.read().await.get(&client_id) {
+                                            None => {
+                                                log::warn!(
+                                                    "found updates for unavailable client ({})",
+                                                    client_id
+                                                )
+                                            }
+                                            Some(ctx) => {
+                                                match &update {
+                                                    Update::TermsOfService(tos)
+                                                        if auto_accept_tos =>
+                                                    {
+                                                        spawn_accept_terms_of_service(
+                                                            ctx.client().clone(),
+                                                            tos.terms_of_service_id().clone(),
+                                                        )
                                                     }
-                                                };
+                                                    _ => {}
+                                                }
+                                                ctx.client().cache().handle_update(&update);
+                                                ctx.client().broadcast_update(&update);
+                                                for subscriber in ctx.client().update_subscribers()
+                                                {
+                                                    deliver_to_subscriber(
+                                                        &subscriber,
+                                                        Box::new(update.clone()),
+                                                        drop_policy,
+                                                    );
+                                                }
+                                                if let Some(sender) = ctx.client().updates_sender()
+                                                {
+                                                    log::trace!("sending update to client");
+                                                    deliver_update(
+                                                        sender,
+                                                        ctx.updates_delivery(),
+                                                        Box::new(update),
+                                                        drop_policy,
+                                                        send_timeout,
+                                                    )
+                                                    .await;
+                                                }
                                             }
                                         }
                                     }
                                 }
-                            }
+                            };
+                            #[cfg(feature = "tracing")]
+                            let handle_received =
+                                tracing::Instrument::instrument(handle_received, span);
+                            handle_received.await;
                         }
                     };
+                } else {
+                    if idle_since.elapsed() >= idle_timeout {
+                        log::debug!("nothing received from tdlib within {:?}", idle_timeout);
+                        idle_since = Instant::now();
+                    }
+                    if drop_policy == DropPolicy::DropOldest {
+                        // no new data, so deliver queued updates to clients which caught up meanwhile
+                        for ctx in clients.read().await.values() {
+                            if let Some(sender) = ctx.client().updates_sender() {
+                                ctx.updates_delivery().flush(sender);
+                            }
+                            for subscriber in ctx.client().update_subscribers() {
+                                subscriber.delivery.flush(&subscriber.sender);
+                            }
+                        }
+                    }
                 }
             }
         })
@@ -431,7 +1215,8 @@ where
                     client,
                     ctx.pub_state_message_sender(),
                     ctx.private_state_message_sender(),
-                    self.auth_state_handler.as_ref(),
+                    ctx.state_sender(),
+                    ctx.auth_state_handler(self.auth_state_handler.as_ref()),
                     auth_state,
                     self.channels_send_timeout,
                 )
@@ -448,51 +1233,85 @@ where
         let auth_state_handler = self.auth_state_handler.clone();
         let clients = self.clients.clone();
         let send_timeout = self.channels_send_timeout;
+        let auth_timeout = self.auth_timeout;
+        let auto_reconnect = self.auto_reconnect;
 
         tokio::spawn(async move {
             while let Some(auth_state) = auth_rx.recv().await {
                 log::debug!("received new auth state: {:?}", auth_state);
                 if let Some(client_id) = auth_state.client_id() {
-                    let result = match clients.read().await.get(&client_id) {
-                        None => {
-                            log::warn!("found auth updates for unavailable client ({})", client_id);
-                            continue;
-                        }
-                        Some(client_ctx) => {
-                            handle_auth_state(
-                                client_ctx.client(),
-                                client_ctx.pub_state_message_sender(),
-                                client_ctx.private_state_message_sender(),
-                                auth_state_handler.as_ref(),
-                                &auth_state.authorization_state(),
-                                send_timeout,
-                            )
-                            .await
-                        }
-                    };
+                    #[cfg(feature = "tracing")]
+                    let span = tracing::debug_span!(
+                        "auth_state",
+                        client_id,
+                        extra = ?auth_state.extra()
+                    );
+                    let handle_auth = async {
+                        let result = match clients.read().await.get(&client_id) {
+                            None => {
+                                log::warn!(
+                                    "found auth updates for unavailable client ({})",
+                                    client_id
+                                );
+                                return;
+                            }
+                            Some(client_ctx) => {
+                                handle_auth_state(
+                                    client_ctx.client(),
+                                    client_ctx.pub_state_message_sender(),
+                                    client_ctx.private_state_message_sender(),
+                                    client_ctx.state_sender(),
+                                    client_ctx.auth_state_handler(auth_state_handler.as_ref()),
+                                    auth_state.authorization_state(),
+                                    send_timeout,
+                                )
+                                .await
+                            }
+                        };
 
-                    match result {
-                        Ok(_) => {
-                            log::debug!("state changes handled properly")
-                        }
-                        Err(err) => {
-                            match clients.read().await.get(&client_id) {
-                                None => {
-                                    log::error!("client not found")
+                        match result {
+                            Ok(_) => {
+                                if let AuthorizationState::Ready(_) =
+                                    auth_state.authorization_state()
+                                {
+                                    if let Some(ctx) = clients.read().await.get(&client_id) {
+                                        ctx.reconnect_attempts().store(0, Ordering::Release);
+                                    }
                                 }
-                                Some(cl) => {
-                                    if let Some(state_sender) = cl.pub_state_message_sender() {
-                                        if let Err(err) = state_sender
-                                            .send_timeout(Err((err, auth_state)), send_timeout)
-                                            .await
-                                        {
-                                            log::error!("cannot send client state changes: {}", err)
-                                        }
+                                log::debug!("state changes handled properly")
+                            }
+                            Err(err) => {
+                                let ctx = match clients.read().await.get(&client_id) {
+                                    None => {
+                                        log::error!("client not found");
+                                        return;
+                                    }
+                                    Some(ctx) => ctx.clone(),
+                                };
+                                match auto_reconnect {
+                                    Some(auto_reconnect) if is_connection_error(&err) => {
+                                        log::warn!(
+                                            "client {} failed: {}, reconnecting",
+                                            client_id,
+                                            err
+                                        );
+                                        tokio::spawn(reconnect_client(
+                                            clients.clone(),
+                                            client_id,
+                                            auto_reconnect,
+                                            auth_timeout,
+                                            send_timeout,
+                                            (err, auth_state),
+                                        ));
                                     }
+                                    _ => send_auth_error(&ctx, err, auth_state, send_timeout).await,
                                 }
-                            };
+                            }
                         }
-                    }
+                    };
+                    #[cfg(feature = "tracing")]
+                    let handle_auth = tracing::Instrument::instrument(handle_auth, span);
+                    handle_auth.await;
                 }
             }
         })
@@ -509,10 +1328,19 @@ where
     }
 }
 
-async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = ?client.get_client_id().ok(), extra = ?state.extra())
+    )
+)]
+async fn handle_auth_state<A: AuthStateHandler + Sync + ?Sized, R: TdLibClient + Clone>(
     client: &Client<R>,
     pub_state_sender: &Option<mpsc::Sender<StateMessage>>,
     private_state_sender: &mpsc::Sender<ClientState>,
+    state_sender: &watch::Sender<ClientState>,
     auth_state_handler: &A,
     state: &AuthorizationState,
     send_state_timeout: time::Duration,
@@ -521,7 +1349,10 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
     let mut result_state = None;
     let res = match state {
         AuthorizationState::_Default => Ok(()),
-        AuthorizationState::Closing(_) => Ok(()),
+        AuthorizationState::Closing(_) => {
+            result_state = Some(ClientState::Closing);
+            Ok(())
+        }
         AuthorizationState::LoggingOut(_) => Ok(()),
         AuthorizationState::Closed(_) => {
             result_state = Some(ClientState::Closed);
@@ -533,7 +1364,7 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
             Ok(())
         }
         AuthorizationState::WaitCode(wait_code) => {
-            let code = auth_state_handler.handle_wait_code(wait_code).await;
+            let code = auth_state_handler.handle_wait_code(wait_code).await?;
             client
                 .check_authentication_code(CheckAuthenticationCode::builder().code(code).build())
                 .await?;
@@ -542,7 +1373,7 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
         AuthorizationState::WaitEncryptionKey(wait_encryption_key) => {
             let key = auth_state_handler
                 .handle_encryption_key(wait_encryption_key)
-                .await;
+                .await?;
             log::debug!("checking encryption key");
             client
                 .check_database_encryption_key(
@@ -563,7 +1394,9 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
             Ok(())
         }
         AuthorizationState::WaitPassword(wait_password) => {
-            let password = auth_state_handler.handle_wait_password(wait_password).await;
+            let password = auth_state_handler
+                .handle_wait_password(wait_password)
+                .await?;
             log::debug!("checking password");
             client
                 .check_authentication_password(
@@ -575,24 +1408,42 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
             log::debug!("password checked");
             Ok(())
         }
-        AuthorizationState::WaitPhoneNumber(wait_phone_number) => {
-            let phone_number = auth_state_handler
-                .handle_wait_phone_number(wait_phone_number)
-                .await;
+        AuthorizationState::WaitPhoneNumber(_) if client.qr_login() => {
+            log::debug!("requesting qr code authentication");
             client
-                .set_authentication_phone_number(
-                    SetAuthenticationPhoneNumber::builder()
-                        .phone_number(phone_number)
-                        .build(),
-                )
+                .request_qr_code_authentication(RequestQrCodeAuthentication::builder().build())
                 .await?;
             Ok(())
         }
+        AuthorizationState::WaitPhoneNumber(wait_phone_number) => {
+            match auth_state_handler
+                .handle_wait_client_auth_data(wait_phone_number)
+                .await?
+            {
+                ClientAuthData::Phone(phone_number) => {
+                    client
+                        .set_authentication_phone_number(
+                            SetAuthenticationPhoneNumber::builder()
+                                .phone_number(phone_number)
+                                .build(),
+                        )
+                        .await?;
+                }
+                ClientAuthData::BotToken(token) => {
+                    client
+                        .check_authentication_bot_token(
+                            CheckAuthenticationBotToken::builder().token(token).build(),
+                        )
+                        .await?;
+                }
+            };
+            Ok(())
+        }
         AuthorizationState::WaitRegistration(wait_registration) => {
             log::debug!("handling wait registration");
             let (first_name, last_name) = auth_state_handler
                 .handle_wait_registration(wait_registration)
-                .await;
+                .await?;
             let register = RegisterUser::builder()
                 .first_name(first_name)
                 .last_name(last_name)
@@ -606,7 +1457,7 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
             client
                 .set_tdlib_parameters(
                     SetTdlibParameters::builder()
-                        .parameters(client.tdlib_parameters())
+                        .parameters(client.tdlib_parameters_to_send())
                         .build(),
                 )
                 .await?;
@@ -621,6 +1472,7 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
     match &result_state {
         None => {}
         Some(state) => {
+            state_sender.send_replace(state.clone());
             if let Err(err) = private_state_sender.send(state.clone()).await {
                 {
                     log::error!(
@@ -648,7 +1500,136 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
     res
 }
 
-async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: ClientId) {
+// Sends authorization flow error to client state channel, if the client has one
+async fn send_auth_error<S: TdLibClient + Clone>(
+    ctx: &ClientContext<S>,
+    err: RTDError,
+    auth_state: UpdateAuthorizationState,
+    send_timeout: Duration,
+) {
+    if let Some(state_sender) = ctx.pub_state_message_sender() {
+        if let Err(err) = state_sender
+            .send_timeout(Err((err, auth_state)), send_timeout)
+            .await
+        {
+            log::error!("cannot send client state changes: {}", err)
+        }
+    }
+}
+
+// Recreates TDLib client behind the failed one and triggers authorization flow again; the flow itself is handled by the auth task,
+// which calls this function again if it fails. Gives up and reports the last error after `max_retries` consecutive attempts.
+// Determines whether the client failed because TDLib stopped responding or closed it, so recreating the client may help.
+// Errors of auth state handler and requests rejected by TDLib, e.g. wrong code or password, are not fixed by reconnection
+fn is_connection_error(err: &RTDError) -> bool {
+    match err {
+        RTDError::AuthTimeout | RTDError::RequestTimeout => true,
+        // pending requests of closed client are aborted with internal error
+        RTDError::TDLibError(err) => err.code() == 500,
+        RTDError::Internal(message) => *message == CLOSED_RECEIVER_MESSAGE,
+        _ => false,
+    }
+}
+
+async fn reconnect_client<S>(
+    clients: Arc<RwLock<ClientsMap<S>>>,
+    mut client_id: ClientId,
+    auto_reconnect: AutoReconnect,
+    auth_timeout: Duration,
+    send_timeout: Duration,
+    (mut err, auth_state): (RTDError, UpdateAuthorizationState),
+) where
+    S: TdLibClient + Send + Sync + Clone + 'static,
+{
+    loop {
+        let ctx = match clients.read().await.get(&client_id) {
+            // client closed by user meanwhile
+            None => return,
+            Some(ctx) => ctx.clone(),
+        };
+        let attempt = ctx.reconnect_attempts().fetch_add(1, Ordering::AcqRel);
+        if attempt >= auto_reconnect.max_retries {
+            log::error!(
+                "client {} not reconnected after {} attempts",
+                client_id,
+                attempt
+            );
+            ctx.state_sender().send_replace(ClientState::Closed);
+            send_auth_error(&ctx, err, auth_state, send_timeout).await;
+            return;
+        }
+        time::sleep(
+            auto_reconnect
+                .backoff
+                .saturating_mul(2u32.saturating_pow(attempt)),
+        )
+        .await;
+
+        // failed client must release its database before the new one is created with the same parameters
+        let tdlib_client = ctx.client().get_tdlib_client();
+        let mut state_receiver = ctx.state_sender().subscribe();
+        if let Err(err) = tdlib_client.send(client_id, Close::builder().build()) {
+            log::warn!("can't close failed client {}: {}", client_id, err);
+        }
+        let closed = time::timeout(auth_timeout, async {
+            while *state_receiver.borrow_and_update() != ClientState::Closed {
+                if state_receiver.changed().await.is_err() {
+                    break;
+                }
+            }
+        })
+        .await;
+        if closed.is_err() {
+            log::warn!("failed client {} not closed in time", client_id);
+        }
+
+        let new_client_id = tdlib_client.new_client();
+        {
+            let mut clients = clients.write().await;
+            if clients.remove(&client_id).is_none() {
+                return;
+            }
+            ctx.client().replace_client_id(new_client_id);
+            ctx.state_sender().send_replace(ClientState::Authorizing);
+            clients.insert(new_client_id, ctx);
+        }
+        log::info!("client {} recreated as {}", client_id, new_client_id);
+        client_id = new_client_id;
+
+        // auth flow starts with the first response, see bind_client
+        match first_internal_request(&tdlib_client, client_id, auth_timeout).await {
+            Ok(_) => return,
+            Err(e) => err = e,
+        }
+    }
+}
+
+// Response is handled by the updates task, so request can't be awaited there and runs in background.
+fn spawn_accept_terms_of_service<S>(client: Client<S>, terms_of_service_id: String)
+where
+    S: TdLibClient + Send + Sync + Clone + 'static,
+{
+    tokio::spawn(async move {
+        log::info!("accepting terms of service {}", terms_of_service_id);
+        if let Err(err) = client
+            .accept_terms_of_service(
+                AcceptTermsOfService::builder()
+                    .terms_of_service_id(terms_of_service_id)
+                    .build(),
+            )
+            .await
+        {
+            log::error!("can't accept terms of service: {}", err)
+        }
+    });
+}
+
+// Returns error only if TDLib did not respond in time, other errors are just logged.
+async fn first_internal_request<S: TdLibClient>(
+    tdlib_client: &S,
+    client_id: ClientId,
+    timeout: Duration,
+) -> RTDResult<()> {
     let req = GetApplicationConfig::builder().build();
     let extra = match req.as_ref().extra().ok_or(RTDError::Internal(
         "invalid tdlib response type, not have `extra` field",
@@ -656,17 +1637,21 @@ async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: Cli
         Ok(v) => v,
         Err(err) => {
             log::error!("{}", err);
-            return;
+            return Ok(());
         }
     };
     let signal = OBSERVER.subscribe(&extra);
     if let Err(err) = tdlib_client.send(client_id, req.as_ref()) {
         log::error!("{}", err);
-        return;
+        return Ok(());
     };
 
-    let received = signal.await;
+    let received = time::timeout(timeout, signal).await;
     OBSERVER.unsubscribe(&extra);
+    let received = match received {
+        Err(_) => return Err(RTDError::AuthTimeout),
+        Ok(received) => received,
+    };
     match received {
         Err(_) => log::error!("receiver already closed"),
         Ok(v) => match v {
@@ -677,17 +1662,25 @@ async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: Cli
             }
         },
     };
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::client::client::Client;
+    use crate::client::client::{Client, ClientState};
     use crate::client::tdlib_client::TdLibClient;
-    use crate::client::worker::Worker;
-    use crate::errors::RTDResult;
+    use crate::client::worker::{DropPolicy, Worker};
+    use crate::client::{MockTdLibClient, WorkerMetrics};
+    use crate::errors::{RTDError, RTDResult};
     use crate::tdjson;
-    use crate::types::{Chats, RFunction, RObject, SearchPublicChats, TdlibParameters};
+    use crate::types::{
+        AuthorizationState, AuthorizationStateReady, Chats, RFunction, RObject, SearchPublicChats,
+        TdlibParameters, Update,
+    };
+    use futures::StreamExt;
+    use std::sync::Arc;
     use std::time::Duration;
+    use tokio::sync::mpsc;
     use tokio::time::timeout;
 
     #[derive(Clone)]
@@ -769,6 +1762,34 @@ mod tests {
         };
     }
 
+    #[tokio::test]
+    async fn test_auth_timeout() {
+        let mocked_raw_api = MockedRawApi::new();
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mocked_raw_api.clone())
+            .with_auth_timeout(0.05)
+            .build()
+            .unwrap();
+        worker.start();
+        let res = timeout(
+            Duration::from_secs(1),
+            worker.bind_client(
+                Client::builder()
+                    .with_tdlib_client(mocked_raw_api.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            ),
+        )
+        .await;
+        match res {
+            Err(_) => panic!("bind_client not timed out"),
+            Ok(Err(RTDError::AuthTimeout)) => {}
+            Ok(Err(e)) => panic!("{}", e),
+            Ok(Ok(_)) => panic!("error not raised"),
+        };
+    }
+
     #[tokio::test]
     async fn test_request_flow() {
         let mut mocked_raw_api = MockedRawApi::new();
@@ -820,4 +1841,266 @@ mod tests {
             Ok(Ok(result)) => assert_eq!(result.chat_ids(), &vec![1, 2, 3]),
         }
     }
+
+    #[tokio::test]
+    async fn test_request_history() {
+        let mut mocked_raw_api = MockedRawApi::new();
+
+        let search_req = SearchPublicChats::builder().build();
+        let extra = search_req.extra().unwrap().to_string();
+        let to_receive = serde_json::json!({
+            "@type": "chats",
+            "@client_id": 1,
+            "@extra": extra,
+            "total_count": 0,
+            "chat_ids": [],
+        })
+        .to_string();
+        mocked_raw_api.set_to_receive(to_receive.clone());
+
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mocked_raw_api.clone())
+            .with_request_history(1)
+            .build()
+            .unwrap();
+        worker.start();
+
+        let client = worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mocked_raw_api.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+
+        match timeout(
+            Duration::from_secs(10),
+            client.search_public_chats(search_req),
+        )
+        .await
+        {
+            Err(_) => panic!("did not receive response within 10 s"),
+            Ok(Err(e)) => panic!("{}", e),
+            Ok(Ok(_)) => {}
+        }
+
+        let history = worker.request_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].extra(), extra);
+        assert!(history[0].request().contains("searchPublicChats"));
+        assert_eq!(history[0].response(), Some(to_receive.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_client_state_stream() {
+        let mocked_raw_api = MockedRawApi::new();
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mocked_raw_api.clone())
+            .build()
+            .unwrap();
+        let client = worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mocked_raw_api.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+
+        let states = worker
+            .client_state_stream(client.get_client_id().unwrap())
+            .await
+            .unwrap();
+        futures::pin_mut!(states);
+        assert_eq!(states.next().await, Some(ClientState::Authorizing));
+
+        worker
+            .handle_auth_state(
+                &AuthorizationState::Ready(AuthorizationStateReady::builder().build()),
+                &client,
+            )
+            .await
+            .unwrap();
+        assert_eq!(states.next().await, Some(ClientState::Opened));
+
+        // late subscriber receives the current state
+        let late_states = worker
+            .client_state_stream(client.get_client_id().unwrap())
+            .await
+            .unwrap();
+        futures::pin_mut!(late_states);
+        assert_eq!(late_states.next().await, Some(ClientState::Opened));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_update_skipped() {
+        let to_receive = vec![
+            "not a json".to_string(),
+            r#"{"@type":"updateFromFutureVersion","@client_id":1}"#.to_string(),
+            r#"{"@type":"updateOption","name":"version"}"#.to_string(),
+        ];
+        let mock = MockTdLibClient::new();
+        for json in &to_receive {
+            mock.push_received(json.clone());
+        }
+        let (unknown_sx, mut unknown_rx) = mpsc::unbounded_channel();
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock)
+            .with_unknown_update_handler(move |json| {
+                unknown_sx.send(json.to_string()).unwrap();
+            })
+            .build()
+            .unwrap();
+        let handle = worker.start();
+
+        for expected in &to_receive {
+            let json = timeout(Duration::from_secs(10), unknown_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(&json, expected);
+        }
+        assert!(worker.readiness().await.tasks_running());
+        assert!(!handle.is_finished());
+        worker.stop();
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingMetrics {
+        events: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl WorkerMetrics for RecordingMetrics {
+        fn on_update_received(&self, td_type: &str) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("update {}", td_type));
+        }
+        fn on_request_sent(&self, client_id: tdjson::ClientId) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("sent {}", client_id));
+        }
+        fn on_response_received(&self, client_id: tdjson::ClientId, _elapsed: Duration) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("response {}", client_id));
+        }
+        fn on_error(&self, _error: &RTDError) {
+            self.events.lock().unwrap().push("error".to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let to_receive = vec![
+            "not a json".to_string(),
+            serde_json::json!({
+                "@type": "updateOption",
+                "@client_id": 1,
+                "name": "version",
+                "value": {"@type": "optionValueString", "value": "1.7.0"},
+            })
+            .to_string(),
+        ];
+        let mock = MockTdLibClient::new();
+        for json in to_receive {
+            mock.push_received(json);
+        }
+        mock.enqueue_response(
+            "searchPublicChats",
+            serde_json::json!({"@type": "chats", "total_count": 0, "chat_ids": []}),
+        );
+        let metrics = RecordingMetrics::default();
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+        worker.start();
+        let client = worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mock)
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+
+        timeout(
+            Duration::from_secs(10),
+            client.search_public_chats(SearchPublicChats::builder().build()),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let mut events = metrics.events.lock().unwrap().clone();
+        events.sort();
+        assert_eq!(
+            events,
+            vec!["error", "response 1", "sent 1", "update updateOption"]
+        );
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_policy() {
+        let mock = MockTdLibClient::new();
+        for name in &["a", "b", "c"] {
+            mock.push_update(
+                1,
+                serde_json::json!({
+                    "@type": "updateOption",
+                    "name": name,
+                    "value": {"@type": "optionValueEmpty"},
+                }),
+            );
+        }
+        let (updates_sx, mut updates_rx) = mpsc::channel(1);
+        let mut worker = Worker::builder()
+            .with_tdlib_client(mock.clone())
+            .with_channels_send_timeout(0.05)
+            .with_stop_poll_timeout(0.05)
+            .with_drop_policy(DropPolicy::DropOldest)
+            .build()
+            .unwrap();
+        worker
+            .set_client(
+                Client::builder()
+                    .with_tdlib_client(mock)
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_updates_sender(updates_sx)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        worker.start();
+
+        timeout(Duration::from_secs(10), async {
+            while worker.dropped_updates(1).await.unwrap() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        for expected in &["a", "c"] {
+            let update = timeout(Duration::from_secs(10), updates_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            match *update {
+                Update::Option(option) => assert_eq!(option.name(), expected),
+                _ => panic!("invalid update: {:?}", update),
+            }
+        }
+        assert_eq!(worker.dropped_updates(1).await.unwrap(), 1);
+        worker.stop();
+    }
 }
@@ -9,6 +9,16 @@ pub enum RTDError {
     TDLibError(TDLibError),
     Internal(&'static str),
     BadRequest(&'static str),
+    /// TDLib did not respond during client authorization within configured timeout.
+    AuthTimeout,
+    /// Request parameters rejected by client-side validation, before sending to TDLib.
+    InvalidParameters(&'static str),
+    /// TDLib rejected the request because of flood limits, it can be repeated after `retry_after` seconds.
+    FloodWait {
+        retry_after: u64,
+    },
+    /// TDLib did not respond to the request within [configured timeout](crate::client::client::ClientBuilder::with_request_timeout).
+    RequestTimeout,
 }
 
 pub type RTDResult<T, E = RTDError> = Result<T, E>;
@@ -31,6 +41,18 @@ impl fmt::Display for RTDError {
             RTDError::BadRequest(err) => {
                 write!(f, "{}", err)
             }
+            RTDError::AuthTimeout => {
+                write!(f, "no response from tdlib during authorization")
+            }
+            RTDError::InvalidParameters(err) => {
+                write!(f, "invalid parameters: {}", err)
+            }
+            RTDError::FloodWait { retry_after } => {
+                write!(f, "too many requests, retry after {} seconds", retry_after)
+            }
+            RTDError::RequestTimeout => {
+                write!(f, "no response from tdlib within request timeout")
+            }
         }
     }
 }
@@ -43,6 +65,10 @@ impl error::Error for RTDError {
             RTDError::Internal(_) => None,
             RTDError::TDLibError(_) => None,
             RTDError::BadRequest(_) => None,
+            RTDError::AuthTimeout => None,
+            RTDError::InvalidParameters(_) => None,
+            RTDError::FloodWait { .. } => None,
+            RTDError::RequestTimeout => None,
         }
     }
 }
@@ -59,6 +85,16 @@ impl From<serde_json::Error> for RTDError {
     }
 }
 
+// Extracts delay from TDLib errors like `Too Many Requests: retry after 30`
+#[cfg(feature = "client")]
+pub(crate) fn flood_wait_retry_after(err: &TDLibError) -> Option<u64> {
+    if err.code() != 429 {
+        return None;
+    }
+    let (_, retry_after) = err.message().rsplit_once("retry after ")?;
+    retry_after.trim().parse().ok()
+}
+
 const CLOSED_CHANNEL_ERROR: RTDError = RTDError::Internal("channel closed");
 const SEND_TO_CHANNEL_TIMEOUT: RTDError = RTDError::Internal("timeout for mpsc occurred");
 
@@ -71,3 +107,29 @@ impl<T> From<tokio::sync::mpsc::error::SendTimeoutError<T>> for RTDError {
         }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use crate::errors::{flood_wait_retry_after, TDLibError};
+
+    #[test]
+    fn test_flood_wait_retry_after() {
+        let err = TDLibError::builder()
+            .code(429)
+            .message("Too Many Requests: retry after 30")
+            .build();
+        assert_eq!(flood_wait_retry_after(&err), Some(30));
+
+        let err = TDLibError::builder()
+            .code(429)
+            .message("Too Many Requests")
+            .build();
+        assert_eq!(flood_wait_retry_after(&err), None);
+
+        let err = TDLibError::builder()
+            .code(400)
+            .message("Bad Request: retry after 30")
+            .build();
+        assert_eq!(flood_wait_retry_after(&err), None);
+    }
+}
@@ -3,8 +3,29 @@ use std::ffi::CStr;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_double, c_int, c_long};
 use std::ptr;
+#[cfg(feature = "tdlib-1-8")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "tdlib-1-8")]
+use std::sync::{Mutex, RwLock};
 
 pub type ClientId = i32;
+
+/// Verbosity levels of TDLib internal logging, see [set_log_verbosity_level](crate::tdjson::set_log_verbosity_level).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Fatal = 0,
+    Error = 1,
+    Warning = 2,
+    Info = 3,
+    Debug = 4,
+    Verbose = 5,
+}
+
+impl From<LogLevel> for i32 {
+    fn from(level: LogLevel) -> Self {
+        level as i32
+    }
+}
 #[link(name = "tdjson")]
 extern "C" {
     fn td_create_client_id() -> c_int;
@@ -12,14 +33,22 @@ extern "C" {
     fn td_receive(timeout: c_double) -> *const c_char;
     fn td_execute(request: *const c_char) -> *const c_char;
 
-    // Deprecated. Use setLogVerbosityLevel request instead.
-    fn td_set_log_verbosity_level(level: c_int);
     // Deprecated. Use setLogStream request instead.
     fn td_set_log_file_path(path: *const c_char) -> c_int;
     // Deprecated. Use setLogStream request instead.
     fn td_set_log_max_file_size(size: c_long);
 }
 
+// Available since TDLib 1.8.0
+#[cfg(feature = "tdlib-1-8")]
+#[link(name = "tdjson")]
+extern "C" {
+    fn td_set_log_message_callback(
+        max_verbosity_level: c_int,
+        callback: Option<extern "C" fn(c_int, *const c_char)>,
+    );
+}
+
 pub fn new_client() -> ClientId {
     unsafe { td_create_client_id() }
 }
@@ -47,9 +76,14 @@ pub fn receive(timeout: f64) -> Option<String> {
     }
 }
 
-// Deprecated. Use setLogVerbosityLevel request instead.
-pub fn set_log_verbosity_level(level: i32) {
-    unsafe { td_set_log_verbosity_level(level) };
+/// Sets verbosity level of TDLib internal logging, from 0 (fatal errors only) to 1023; values from [LogLevel](crate::tdjson::LogLevel) cover common cases.
+/// TDLib logs with level 5 by default.
+pub fn set_log_verbosity_level<L: Into<i32>>(level: L) {
+    let request = format!(
+        r#"{{"@type":"setLogVerbosityLevel","new_verbosity_level":{}}}"#,
+        level.into()
+    );
+    execute(&request);
 }
 
 // Deprecated. Use setLogStream request instead.
@@ -72,3 +106,105 @@ pub fn set_log_file_path(path: Option<&str>) -> bool {
 pub fn set_log_max_file_size(size: i64) {
     unsafe { td_set_log_max_file_size(size as c_long) };
 }
+
+/// Callback receiving TDLib internal log messages, see [set_log_message_callback](crate::tdjson::set_log_message_callback).
+#[cfg(feature = "tdlib-1-8")]
+pub type LogMessageCallback = Box<dyn Fn(i32, &str) + Send + Sync>;
+
+// Callback along with the number of its guard, so guard of replaced callback doesn't remove the new one
+#[cfg(feature = "tdlib-1-8")]
+static LOG_MESSAGE_CALLBACK: RwLock<Option<(u64, LogMessageCallback)>> = RwLock::new(None);
+#[cfg(feature = "tdlib-1-8")]
+static LOG_MESSAGE_CALLBACK_COUNTER: AtomicU64 = AtomicU64::new(0);
+// Serializes installing and removing of the callback, so guard checks it's current and unsets it at once.
+// Trampoline doesn't take it, so TDLib calling the callback while it's unset doesn't deadlock
+#[cfg(feature = "tdlib-1-8")]
+static LOG_MESSAGE_CALLBACK_INSTALL: Mutex<()> = Mutex::new(());
+
+#[cfg(feature = "tdlib-1-8")]
+extern "C" fn log_message_trampoline(verbosity_level: c_int, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    // unwinding into TDLib is undefined behavior, so panics of the callback are swallowed
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        if let Ok(callback) = LOG_MESSAGE_CALLBACK.read() {
+            if let Some((_, callback)) = callback.as_ref() {
+                callback(verbosity_level, &message);
+            }
+        }
+    }));
+}
+
+/// Keeps [log message callback](crate::tdjson::set_log_message_callback) installed, the callback is removed when the guard dropped.
+#[cfg(feature = "tdlib-1-8")]
+#[derive(Debug)]
+#[must_use = "callback is removed when the guard dropped"]
+pub struct LogMessageCallbackGuard {
+    id: u64,
+}
+
+#[cfg(feature = "tdlib-1-8")]
+impl LogMessageCallbackGuard {
+    fn is_current(&self) -> bool {
+        LOG_MESSAGE_CALLBACK
+            .read()
+            .map(|callback| matches!(callback.as_ref(), Some((id, _)) if *id == self.id))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(feature = "tdlib-1-8")]
+impl Drop for LogMessageCallbackGuard {
+    fn drop(&mut self) {
+        let _install = LOG_MESSAGE_CALLBACK_INSTALL
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if !self.is_current() {
+            return;
+        }
+        // TDLib may be calling the callback right now, so it's unset without holding the callback lock
+        unsafe { td_set_log_message_callback(0, None) };
+        if let Ok(mut callback) = LOG_MESSAGE_CALLBACK.write() {
+            callback.take();
+        }
+    }
+}
+
+/// Routes TDLib internal log messages with verbosity level up to `max_verbosity_level` to `callback`, instead of or in addition to the log stream.
+/// Callback receives verbosity level and text of each message; message with level 0 is a fatal error, after which the process is terminated.
+/// Requires TDLib 1.8.0 or newer, available with `tdlib-1-8` feature. Only one callback can be installed, setting new callback replaces the previous one.
+///
+/// Callback is called synchronously from TDLib internal threads, possibly from several threads at once, so it must be fast,
+/// must not block and must not call TDLib methods.
+#[cfg(feature = "tdlib-1-8")]
+pub fn set_log_message_callback(
+    max_verbosity_level: i32,
+    callback: LogMessageCallback,
+) -> LogMessageCallbackGuard {
+    let _install = LOG_MESSAGE_CALLBACK_INSTALL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let id = LOG_MESSAGE_CALLBACK_COUNTER.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut current) = LOG_MESSAGE_CALLBACK.write() {
+        *current = Some((id, callback));
+    }
+    unsafe { td_set_log_message_callback(max_verbosity_level, Some(log_message_trampoline)) };
+    LogMessageCallbackGuard { id }
+}
+
+#[cfg(all(test, feature = "tdlib-1-8"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dropping_replaced_guard_keeps_new_callback() {
+        let old_guard = set_log_message_callback(1, Box::new(|_, _| {}));
+        let new_guard = set_log_message_callback(1, Box::new(|_, _| {}));
+        drop(old_guard);
+        assert!(new_guard.is_current());
+        drop(new_guard);
+        assert!(LOG_MESSAGE_CALLBACK.read().unwrap().is_none());
+    }
+}
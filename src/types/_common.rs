@@ -3,6 +3,9 @@ use std::{
     str::FromStr,
 };
 
+#[cfg(any(test, feature = "testing"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use serde::de::{Deserialize, Deserializer, Error as SerdeError};
 
 use crate::{errors::*, types::*};
@@ -16,6 +19,37 @@ where
     Ok(serde_json::from_str(json)?)
 }
 
+/// Generates `@extra` values, used to match TDLib responses with sent requests, see [with_extra_generator](crate::client::client::ClientBuilder::with_extra_generator).
+/// Generated values must be unique within the process.
+pub trait ExtraGenerator: Send + Sync {
+    fn generate(&self) -> String;
+}
+
+/// Default generator, produces UUID v4 values.
+#[derive(Debug, Default)]
+pub struct UuidExtraGenerator;
+
+impl ExtraGenerator for UuidExtraGenerator {
+    fn generate(&self) -> String {
+        uuid::Uuid::new_v4().to_string()
+    }
+}
+
+/// Produces sequential values (`"1"`, `"2"`, ...), so requests are reproducible in tests.
+/// Values are the same for every instance, so it's available only with `testing` feature and must not be shared by clients used simultaneously.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Default)]
+pub struct SequentialExtraGenerator {
+    counter: AtomicU64,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl ExtraGenerator for SequentialExtraGenerator {
+    fn generate(&self) -> String {
+        (self.counter.fetch_add(1, Ordering::Relaxed) + 1).to_string()
+    }
+}
+
 /// All tdlib type abstract class defined the same behavior
 pub trait RObject: Debug {
     #[doc(hidden)]
@@ -50,6 +84,44 @@ impl<'a, RObj: RObject> RObject for &'a mut RObj {
 impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
 impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
 
+/// TDLib function which can be executed synchronously, without network requests, see [Client::execute](crate::client::Client::execute).
+pub trait SyncFunction: RFunction {
+    /// Type of the successful response.
+    type Response: serde::de::DeserializeOwned;
+}
+
+macro_rules! impl_sync_function {
+    ($($function:ty => $response:ty),* $(,)?) => {
+        $(
+            impl SyncFunction for $function {
+                type Response = $response;
+            }
+        )*
+    };
+}
+
+impl_sync_function!(
+    AddLogMessage => Ok,
+    CleanFileName => Text,
+    GetFileExtension => Text,
+    GetFileMimeType => Text,
+    GetJsonString => Text,
+    GetJsonValue => JsonValue,
+    GetLanguagePackString => LanguagePackStringValue,
+    GetLogStream => LogStream,
+    GetLogTagVerbosityLevel => LogVerbosityLevel,
+    GetLogTags => LogTags,
+    GetLogVerbosityLevel => LogVerbosityLevel,
+    GetMarkdownText => FormattedText,
+    GetPushReceiverId => PushReceiverId,
+    GetTextEntities => TextEntities,
+    ParseMarkdown => FormattedText,
+    ParseTextEntities => FormattedText,
+    SetLogStream => Ok,
+    SetLogTagVerbosityLevel => Ok,
+    SetLogVerbosityLevel => Ok,
+);
+
 impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType
     for &'a AUTHENTICATIONCODETYPE
 {
@@ -1490,7 +1562,129 @@ where
 #[cfg(test)]
 mod tests {
     use crate::types::_common::deserialize_update;
-    use crate::types::{from_json, AuthorizationState, TdType, Update};
+    use crate::types::{
+        from_json, AuthorizationState, ExtraGenerator, FormattedTextBuilder, Message,
+        MessageSchedulingStateSendAtDate, MessageSender, MessageSenderChat, MessageSenderUser,
+        SequentialExtraGenerator, TdType, TextEntityType, Update, UpdateChatTitle,
+        UpdateNewMessage, UpdateVariant,
+    };
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn test_sequential_extra_generator() {
+        let generator = SequentialExtraGenerator::default();
+        assert_eq!(generator.generate(), "1");
+        assert_eq!(generator.generate(), "2");
+    }
+
+    #[test]
+    fn test_message_is_from_me() {
+        let message = Message::builder()
+            .sender(MessageSender::User(
+                MessageSenderUser::builder().user_id(1).build(),
+            ))
+            .is_outgoing(true)
+            .build();
+        assert!(message.is_from_me(1));
+        assert!(!message.is_from_me(2));
+
+        let channel_post = Message::builder()
+            .sender(MessageSender::Chat(
+                MessageSenderChat::builder().chat_id(-100).build(),
+            ))
+            .is_outgoing(false)
+            .build();
+        assert!(!channel_post.is_from_me(1));
+    }
+
+    #[test]
+    fn test_formatted_text_builder() {
+        let text = FormattedTextBuilder::new()
+            .text("👋 ")
+            .bold("hi")
+            .text(" ")
+            .link("𝕏", "https://example.com")
+            .italic("")
+            .build();
+        assert_eq!(text.text(), "👋 hi 𝕏");
+        assert_eq!(text.entities().len(), 2);
+        let bold = &text.entities()[0];
+        assert_eq!((bold.offset(), bold.length()), (3, 2));
+        assert!(matches!(bold.type_(), TextEntityType::Bold(_)));
+        let link = &text.entities()[1];
+        assert_eq!((link.offset(), link.length()), (6, 2));
+        match link.type_() {
+            TextEntityType::TextUrl(url) => assert_eq!(url.url(), "https://example.com"),
+            _ => panic!("invalid entity type: {:?}", link.type_()),
+        }
+    }
+
+    #[test]
+    fn test_send_at_date_try_build() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i32;
+        let day = 24 * 60 * 60;
+
+        let state = MessageSchedulingStateSendAtDate::builder()
+            .send_date(now + day)
+            .try_build()
+            .unwrap();
+        assert_eq!(state.send_date(), now + day);
+        assert!(MessageSchedulingStateSendAtDate::builder()
+            .send_date(now - day)
+            .try_build()
+            .is_err());
+        assert!(MessageSchedulingStateSendAtDate::builder()
+            .send_date(now + 368 * day)
+            .try_build()
+            .is_err());
+        // unchecked path is kept as is
+        assert_eq!(
+            MessageSchedulingStateSendAtDate::builder()
+                .send_date(now - day)
+                .build()
+                .send_date(),
+            now - day
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_send_at_date_chrono() {
+        use chrono::{DateTime, Duration, Utc};
+
+        let send_at = DateTime::<Utc>::from(SystemTime::now()) + Duration::days(1);
+        let state = MessageSchedulingStateSendAtDate::builder()
+            .send_at(send_at)
+            .try_build()
+            .unwrap();
+        assert_eq!(state.send_date() as i64, send_at.timestamp());
+        assert_eq!(state.send_date_utc().timestamp(), send_at.timestamp());
+    }
+
+    #[test]
+    fn test_update_variant() {
+        let update: Update =
+            from_json(r#"{"@type":"updateChatTitle","chat_id":1,"title":"t"}"#).unwrap();
+        let title = UpdateChatTitle::from_update(update.clone()).unwrap();
+        assert_eq!(title.chat_id(), 1);
+        assert_eq!(title.title(), "t");
+        assert!(UpdateNewMessage::from_update(update).is_none());
+    }
+
+    #[cfg(feature = "strict-deserialization")]
+    #[test]
+    fn test_update_extra_fields() {
+        let update: Update = from_json(
+            r#"{"@type":"updateChatTitle","chat_id":1,"title":"t","unknown_field":{"a":1}}"#,
+        )
+        .unwrap();
+        let extra_fields = update.extra_fields().unwrap();
+        assert_eq!(extra_fields.len(), 1);
+        assert_eq!(extra_fields["unknown_field"], serde_json::json!({"a": 1}));
+    }
 
     #[test]
     fn test_deserialize_enums() {
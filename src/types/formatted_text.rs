@@ -47,6 +47,127 @@ impl FormattedText {
     }
 }
 
+/// Composes [FormattedText](crate::types::FormattedText) of plain and styled runs, e.g.
+/// `FormattedTextBuilder::new().bold("Hello").text(", ").link("world", "https://example.com").build()`.
+/// Entity offsets and lengths are computed in UTF-16 code units, as TDLib expects, so emoji and other non-BMP characters are handled correctly.
+#[derive(Debug, Clone, Default)]
+pub struct FormattedTextBuilder {
+    text: String,
+    entities: Vec<TextEntity>,
+    utf16_length: i32,
+}
+
+impl FormattedTextBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends plain text.
+    pub fn text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.push(text.as_ref());
+        self
+    }
+
+    /// Appends text with the entity of specified type, covering the whole appended text.
+    pub fn styled<T: AsRef<str>, E: AsRef<TextEntityType>>(mut self, text: T, type_: E) -> Self {
+        let offset = self.utf16_length;
+        self.push(text.as_ref());
+        let length = self.utf16_length - offset;
+        if length > 0 {
+            self.entities.push(
+                TextEntity::builder()
+                    .offset(offset)
+                    .length(length)
+                    .type_(type_)
+                    .build(),
+            );
+        }
+        self
+    }
+
+    pub fn bold<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Bold(TextEntityTypeBold::builder().build()),
+        )
+    }
+
+    pub fn italic<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Italic(TextEntityTypeItalic::builder().build()),
+        )
+    }
+
+    pub fn underline<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Underline(TextEntityTypeUnderline::builder().build()),
+        )
+    }
+
+    pub fn strikethrough<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Strikethrough(TextEntityTypeStrikethrough::builder().build()),
+        )
+    }
+
+    pub fn code<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Code(TextEntityTypeCode::builder().build()),
+        )
+    }
+
+    pub fn pre<T: AsRef<str>>(self, text: T) -> Self {
+        self.styled(
+            text,
+            TextEntityType::Pre(TextEntityTypePre::builder().build()),
+        )
+    }
+
+    /// Appends code block with specified programming language.
+    pub fn pre_code<T: AsRef<str>, L: AsRef<str>>(self, text: T, language: L) -> Self {
+        self.styled(
+            text,
+            TextEntityType::PreCode(TextEntityTypePreCode::builder().language(language).build()),
+        )
+    }
+
+    /// Appends text which opens `url` when clicked.
+    pub fn link<T: AsRef<str>, U: AsRef<str>>(self, text: T, url: U) -> Self {
+        self.styled(
+            text,
+            TextEntityType::TextUrl(TextEntityTypeTextUrl::builder().url(url).build()),
+        )
+    }
+
+    /// Appends mention of the user without username.
+    pub fn mention_name<T: AsRef<str>>(self, text: T, user_id: i32) -> Self {
+        self.styled(
+            text,
+            TextEntityType::MentionName(
+                TextEntityTypeMentionName::builder()
+                    .user_id(user_id)
+                    .build(),
+            ),
+        )
+    }
+
+    pub fn build(&self) -> FormattedText {
+        FormattedText::builder()
+            .text(&self.text)
+            .entities(self.entities.clone())
+            .build()
+    }
+
+    fn push(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.utf16_length += text.encode_utf16().count() as i32;
+    }
+}
+
 #[doc(hidden)]
 pub struct RTDFormattedTextBuilder {
     inner: FormattedText,
@@ -219,6 +219,15 @@ impl Message {
     pub fn reply_markup(&self) -> &Option<ReplyMarkup> {
         &self.reply_markup
     }
+
+    /// Determines that the message was sent by the user with `self_id`, i.e. by current user when its [id](crate::types::User::id) passed.
+    /// Messages sent on behalf of a chat (channel posts, anonymous admins) are considered sent by current user only if they're [outgoing](crate::types::Message::is_outgoing).
+    pub fn is_from_me(&self, self_id: i64) -> bool {
+        match &self.sender {
+            MessageSender::User(sender) => sender.user_id() as i64 == self_id,
+            _ => self.is_outgoing,
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -3,6 +3,13 @@ use crate::types::*;
 use uuid::Uuid;
 
 use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+// TDLib rejects messages scheduled more than 367 days in the future
+const MAX_SEND_DATE_DELAY: i64 = 367 * 24 * 60 * 60;
 
 /// Contains information about the time when a scheduled message will be sent
 pub trait TDMessageSchedulingState: Debug + RObject {}
@@ -109,6 +116,12 @@ impl MessageSchedulingStateSendAtDate {
     pub fn send_date(&self) -> i32 {
         self.send_date
     }
+
+    /// Date the message will be sent, as [DateTime].
+    #[cfg(feature = "chrono")]
+    pub fn send_date_utc(&self) -> DateTime<Utc> {
+        Utc.timestamp_opt(i64::from(self.send_date), 0).unwrap()
+    }
 }
 
 #[doc(hidden)]
@@ -121,10 +134,32 @@ impl RTDMessageSchedulingStateSendAtDateBuilder {
         self.inner.clone()
     }
 
+    /// Same as [build](Self::build), but checks that `send_date` is not in the past and within 367 days in the future.
+    pub fn try_build(&self) -> RTDResult<MessageSchedulingStateSendAtDate> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RTDError::Internal("system time is before unix epoch"))?
+            .as_secs() as i64;
+        let send_date = i64::from(self.inner.send_date);
+        if send_date < now || send_date > now + MAX_SEND_DATE_DELAY {
+            return Err(RTDError::InvalidParameters(
+                "send_date must be within 367 days in the future",
+            ));
+        }
+        Ok(self.build())
+    }
+
     pub fn send_date(&mut self, send_date: i32) -> &mut Self {
         self.inner.send_date = send_date;
         self
     }
+
+    /// Sets `send_date` from [DateTime], subsecond part is dropped.
+    #[cfg(feature = "chrono")]
+    pub fn send_at(&mut self, dt: DateTime<Utc>) -> &mut Self {
+        self.inner.send_date = dt.timestamp() as i32;
+        self
+    }
 }
 
 impl AsRef<MessageSchedulingStateSendAtDate> for MessageSchedulingStateSendAtDate {
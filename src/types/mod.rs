@@ -1,6 +1,9 @@
 //! Module provides all TDlib types.
 //! For types details you can see [TDlib API Scheme](https://github.com/tdlib/td/blob/master/td/generate/scheme/td_api.tl)
 pub(crate) use self::_common::from_json;
+#[cfg(any(test, feature = "testing"))]
+pub use self::_common::SequentialExtraGenerator;
+pub use self::_common::{ExtraGenerator, SyncFunction, UuidExtraGenerator};
 pub(crate) use self::_common::{RFunction, RObject, TdType};
 
 #[macro_use]
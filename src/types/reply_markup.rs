@@ -78,6 +78,24 @@ impl ReplyMarkup {
     pub fn _is_default(&self) -> bool {
         matches!(self, ReplyMarkup::_Default)
     }
+
+    /// Returns markup which removes custom keyboard. If `is_personal` is true, keyboard removed only for the mentioned users and the target user of a reply.
+    pub fn remove_keyboard(is_personal: bool) -> Self {
+        ReplyMarkup::RemoveKeyboard(
+            ReplyMarkupRemoveKeyboard::builder()
+                .is_personal(is_personal)
+                .build(),
+        )
+    }
+
+    /// Returns markup which forces a reply to the message. If `is_personal` is true, reply forced only for the mentioned users and the target user of a reply.
+    pub fn force_reply(is_personal: bool) -> Self {
+        ReplyMarkup::ForceReply(
+            ReplyMarkupForceReply::builder()
+                .is_personal(is_personal)
+                .build(),
+        )
+    }
 }
 
 impl AsRef<ReplyMarkup> for ReplyMarkup {
@@ -2,6 +2,8 @@ use crate::errors::*;
 use crate::types::*;
 use uuid::Uuid;
 
+#[cfg(feature = "strict-deserialization")]
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 /// Contains notifications about data changes
@@ -612,12 +614,206 @@ impl Update {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields of the update received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        match self {
+            Update::ActiveNotifications(t) => Some(t.extra_fields()),
+            Update::AnimationSearchParameters(t) => Some(t.extra_fields()),
+            Update::AuthorizationState(t) => Some(t.extra_fields()),
+            Update::BasicGroup(t) => Some(t.extra_fields()),
+            Update::BasicGroupFullInfo(t) => Some(t.extra_fields()),
+            Update::Call(t) => Some(t.extra_fields()),
+            Update::ChatActionBar(t) => Some(t.extra_fields()),
+            Update::ChatDefaultDisableNotification(t) => Some(t.extra_fields()),
+            Update::ChatDraftMessage(t) => Some(t.extra_fields()),
+            Update::ChatFilters(t) => Some(t.extra_fields()),
+            Update::ChatHasScheduledMessages(t) => Some(t.extra_fields()),
+            Update::ChatIsBlocked(t) => Some(t.extra_fields()),
+            Update::ChatIsMarkedAsUnread(t) => Some(t.extra_fields()),
+            Update::ChatLastMessage(t) => Some(t.extra_fields()),
+            Update::ChatNotificationSettings(t) => Some(t.extra_fields()),
+            Update::ChatOnlineMemberCount(t) => Some(t.extra_fields()),
+            Update::ChatPermissions(t) => Some(t.extra_fields()),
+            Update::ChatPhoto(t) => Some(t.extra_fields()),
+            Update::ChatPosition(t) => Some(t.extra_fields()),
+            Update::ChatReadInbox(t) => Some(t.extra_fields()),
+            Update::ChatReadOutbox(t) => Some(t.extra_fields()),
+            Update::ChatReplyMarkup(t) => Some(t.extra_fields()),
+            Update::ChatTitle(t) => Some(t.extra_fields()),
+            Update::ChatUnreadMentionCount(t) => Some(t.extra_fields()),
+            Update::ConnectionState(t) => Some(t.extra_fields()),
+            Update::DeleteMessages(t) => Some(t.extra_fields()),
+            Update::DiceEmojis(t) => Some(t.extra_fields()),
+            Update::FavoriteStickers(t) => Some(t.extra_fields()),
+            Update::File(t) => Some(t.extra_fields()),
+            Update::FileGenerationStart(t) => Some(t.extra_fields()),
+            Update::FileGenerationStop(t) => Some(t.extra_fields()),
+            Update::HavePendingNotifications(t) => Some(t.extra_fields()),
+            Update::InstalledStickerSets(t) => Some(t.extra_fields()),
+            Update::LanguagePackStrings(t) => Some(t.extra_fields()),
+            Update::MessageContent(t) => Some(t.extra_fields()),
+            Update::MessageContentOpened(t) => Some(t.extra_fields()),
+            Update::MessageEdited(t) => Some(t.extra_fields()),
+            Update::MessageInteractionInfo(t) => Some(t.extra_fields()),
+            Update::MessageIsPinned(t) => Some(t.extra_fields()),
+            Update::MessageLiveLocationViewed(t) => Some(t.extra_fields()),
+            Update::MessageMentionRead(t) => Some(t.extra_fields()),
+            Update::MessageSendAcknowledged(t) => Some(t.extra_fields()),
+            Update::MessageSendFailed(t) => Some(t.extra_fields()),
+            Update::MessageSendSucceeded(t) => Some(t.extra_fields()),
+            Update::NewCallSignalingData(t) => Some(t.extra_fields()),
+            Update::NewCallbackQuery(t) => Some(t.extra_fields()),
+            Update::NewChat(t) => Some(t.extra_fields()),
+            Update::NewChosenInlineResult(t) => Some(t.extra_fields()),
+            Update::NewCustomEvent(t) => Some(t.extra_fields()),
+            Update::NewCustomQuery(t) => Some(t.extra_fields()),
+            Update::NewInlineCallbackQuery(t) => Some(t.extra_fields()),
+            Update::NewInlineQuery(t) => Some(t.extra_fields()),
+            Update::NewMessage(t) => Some(t.extra_fields()),
+            Update::NewPreCheckoutQuery(t) => Some(t.extra_fields()),
+            Update::NewShippingQuery(t) => Some(t.extra_fields()),
+            Update::Notification(t) => Some(t.extra_fields()),
+            Update::NotificationGroup(t) => Some(t.extra_fields()),
+            Update::Option(t) => Some(t.extra_fields()),
+            Update::Poll(t) => Some(t.extra_fields()),
+            Update::PollAnswer(t) => Some(t.extra_fields()),
+            Update::RecentStickers(t) => Some(t.extra_fields()),
+            Update::SavedAnimations(t) => Some(t.extra_fields()),
+            Update::ScopeNotificationSettings(t) => Some(t.extra_fields()),
+            Update::SecretChat(t) => Some(t.extra_fields()),
+            Update::SelectedBackground(t) => Some(t.extra_fields()),
+            Update::ServiceNotification(t) => Some(t.extra_fields()),
+            Update::StickerSet(t) => Some(t.extra_fields()),
+            Update::SuggestedActions(t) => Some(t.extra_fields()),
+            Update::Supergroup(t) => Some(t.extra_fields()),
+            Update::SupergroupFullInfo(t) => Some(t.extra_fields()),
+            Update::TermsOfService(t) => Some(t.extra_fields()),
+            Update::TrendingStickerSets(t) => Some(t.extra_fields()),
+            Update::UnreadChatCount(t) => Some(t.extra_fields()),
+            Update::UnreadMessageCount(t) => Some(t.extra_fields()),
+            Update::User(t) => Some(t.extra_fields()),
+            Update::UserChatAction(t) => Some(t.extra_fields()),
+            Update::UserFullInfo(t) => Some(t.extra_fields()),
+            Update::UserPrivacySettingRules(t) => Some(t.extra_fields()),
+            Update::UserStatus(t) => Some(t.extra_fields()),
+            Update::UsersNearby(t) => Some(t.extra_fields()),
+
+            _ => None,
+        }
+    }
     #[doc(hidden)]
     pub fn _is_default(&self) -> bool {
         matches!(self, Update::_Default)
     }
 }
 
+/// Concrete update type which can be extracted from [Update](crate::types::Update), e.g. [UpdateNewMessage](crate::types::UpdateNewMessage).
+pub trait UpdateVariant: Sized {
+    /// Returns the update if it's of this type.
+    fn from_update(update: Update) -> Option<Self>;
+}
+
+macro_rules! impl_update_variant {
+    ($($variant:ident($update:ty)),* $(,)?) => {
+        $(
+            impl UpdateVariant for $update {
+                fn from_update(update: Update) -> Option<Self> {
+                    match update {
+                        Update::$variant(t) => Some(t),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_update_variant!(
+    TestUseUpdate(TestUseUpdate),
+    ActiveNotifications(UpdateActiveNotifications),
+    AnimationSearchParameters(UpdateAnimationSearchParameters),
+    AuthorizationState(UpdateAuthorizationState),
+    BasicGroup(UpdateBasicGroup),
+    BasicGroupFullInfo(UpdateBasicGroupFullInfo),
+    Call(UpdateCall),
+    ChatActionBar(UpdateChatActionBar),
+    ChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
+    ChatDraftMessage(UpdateChatDraftMessage),
+    ChatFilters(UpdateChatFilters),
+    ChatHasScheduledMessages(UpdateChatHasScheduledMessages),
+    ChatIsBlocked(UpdateChatIsBlocked),
+    ChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
+    ChatLastMessage(UpdateChatLastMessage),
+    ChatNotificationSettings(UpdateChatNotificationSettings),
+    ChatOnlineMemberCount(UpdateChatOnlineMemberCount),
+    ChatPermissions(UpdateChatPermissions),
+    ChatPhoto(UpdateChatPhoto),
+    ChatPosition(UpdateChatPosition),
+    ChatReadInbox(UpdateChatReadInbox),
+    ChatReadOutbox(UpdateChatReadOutbox),
+    ChatReplyMarkup(UpdateChatReplyMarkup),
+    ChatTitle(UpdateChatTitle),
+    ChatUnreadMentionCount(UpdateChatUnreadMentionCount),
+    ConnectionState(UpdateConnectionState),
+    DeleteMessages(UpdateDeleteMessages),
+    DiceEmojis(UpdateDiceEmojis),
+    FavoriteStickers(UpdateFavoriteStickers),
+    File(UpdateFile),
+    FileGenerationStart(UpdateFileGenerationStart),
+    FileGenerationStop(UpdateFileGenerationStop),
+    HavePendingNotifications(UpdateHavePendingNotifications),
+    InstalledStickerSets(UpdateInstalledStickerSets),
+    LanguagePackStrings(UpdateLanguagePackStrings),
+    MessageContent(UpdateMessageContent),
+    MessageContentOpened(UpdateMessageContentOpened),
+    MessageEdited(UpdateMessageEdited),
+    MessageInteractionInfo(UpdateMessageInteractionInfo),
+    MessageIsPinned(UpdateMessageIsPinned),
+    MessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
+    MessageMentionRead(UpdateMessageMentionRead),
+    MessageSendAcknowledged(UpdateMessageSendAcknowledged),
+    MessageSendFailed(UpdateMessageSendFailed),
+    MessageSendSucceeded(UpdateMessageSendSucceeded),
+    NewCallSignalingData(UpdateNewCallSignalingData),
+    NewCallbackQuery(UpdateNewCallbackQuery),
+    NewChat(UpdateNewChat),
+    NewChosenInlineResult(UpdateNewChosenInlineResult),
+    NewCustomEvent(UpdateNewCustomEvent),
+    NewCustomQuery(UpdateNewCustomQuery),
+    NewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
+    NewInlineQuery(UpdateNewInlineQuery),
+    NewMessage(UpdateNewMessage),
+    NewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
+    NewShippingQuery(UpdateNewShippingQuery),
+    Notification(UpdateNotification),
+    NotificationGroup(UpdateNotificationGroup),
+    Option(UpdateOption),
+    Poll(UpdatePoll),
+    PollAnswer(UpdatePollAnswer),
+    RecentStickers(UpdateRecentStickers),
+    SavedAnimations(UpdateSavedAnimations),
+    ScopeNotificationSettings(UpdateScopeNotificationSettings),
+    SecretChat(UpdateSecretChat),
+    SelectedBackground(UpdateSelectedBackground),
+    ServiceNotification(UpdateServiceNotification),
+    StickerSet(UpdateStickerSet),
+    SuggestedActions(UpdateSuggestedActions),
+    Supergroup(UpdateSupergroup),
+    SupergroupFullInfo(UpdateSupergroupFullInfo),
+    TermsOfService(UpdateTermsOfService),
+    TrendingStickerSets(UpdateTrendingStickerSets),
+    UnreadChatCount(UpdateUnreadChatCount),
+    UnreadMessageCount(UpdateUnreadMessageCount),
+    User(UpdateUser),
+    UserChatAction(UpdateUserChatAction),
+    UserFullInfo(UpdateUserFullInfo),
+    UserPrivacySettingRules(UpdateUserPrivacySettingRules),
+    UserStatus(UpdateUserStatus),
+    UsersNearby(UpdateUsersNearby),
+);
+
 impl AsRef<Update> for Update {
     fn as_ref(&self) -> &Update {
         self
@@ -632,6 +828,10 @@ pub struct UpdateActiveNotifications {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Lists of active notification groups
     groups: Vec<NotificationGroup>,
 }
@@ -653,6 +853,12 @@ impl UpdateActiveNotifications {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateActiveNotificationsBuilder {
         let mut inner = UpdateActiveNotifications::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -701,6 +907,10 @@ pub struct UpdateAnimationSearchParameters {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Name of the animation search provider
     provider: String,
     /// The new list of emojis suggested for searching
@@ -724,6 +934,12 @@ impl UpdateAnimationSearchParameters {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateAnimationSearchParametersBuilder {
         let mut inner = UpdateAnimationSearchParameters::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -781,6 +997,10 @@ pub struct UpdateAuthorizationState {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New authorization state
 
     #[serde(skip_serializing_if = "AuthorizationState::_is_default")]
@@ -804,6 +1024,12 @@ impl UpdateAuthorizationState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateAuthorizationStateBuilder {
         let mut inner = UpdateAuthorizationState::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -855,6 +1081,10 @@ pub struct UpdateBasicGroup {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the group
     basic_group: BasicGroup,
 }
@@ -876,6 +1106,12 @@ impl UpdateBasicGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateBasicGroupBuilder {
         let mut inner = UpdateBasicGroup::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -924,6 +1160,10 @@ pub struct UpdateBasicGroupFullInfo {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of a basic group
     basic_group_id: i32,
     /// New full information about the group
@@ -947,6 +1187,12 @@ impl UpdateBasicGroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateBasicGroupFullInfoBuilder {
         let mut inner = UpdateBasicGroupFullInfo::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1007,6 +1253,10 @@ pub struct UpdateCall {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about a call
     call: Call,
 }
@@ -1028,6 +1278,12 @@ impl UpdateCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateCallBuilder {
         let mut inner = UpdateCall::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1076,6 +1332,10 @@ pub struct UpdateChatActionBar {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new value of the action bar; may be null
@@ -1099,6 +1359,12 @@ impl UpdateChatActionBar {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatActionBarBuilder {
         let mut inner = UpdateChatActionBar::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1156,6 +1422,10 @@ pub struct UpdateChatDefaultDisableNotification {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new default_disable_notification value
@@ -1179,6 +1449,12 @@ impl UpdateChatDefaultDisableNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatDefaultDisableNotificationBuilder {
         let mut inner = UpdateChatDefaultDisableNotification::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1241,6 +1517,10 @@ pub struct UpdateChatDraftMessage {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new draft message; may be null
@@ -1266,6 +1546,12 @@ impl UpdateChatDraftMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatDraftMessageBuilder {
         let mut inner = UpdateChatDraftMessage::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1332,6 +1618,10 @@ pub struct UpdateChatFilters {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new list of chat filters
     chat_filters: Vec<ChatFilterInfo>,
 }
@@ -1353,6 +1643,12 @@ impl UpdateChatFilters {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatFiltersBuilder {
         let mut inner = UpdateChatFilters::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1401,6 +1697,10 @@ pub struct UpdateChatHasScheduledMessages {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// New value of has_scheduled_messages
@@ -1424,6 +1724,12 @@ impl UpdateChatHasScheduledMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatHasScheduledMessagesBuilder {
         let mut inner = UpdateChatHasScheduledMessages::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1481,6 +1787,10 @@ pub struct UpdateChatIsBlocked {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// New value of is_blocked
@@ -1504,6 +1814,12 @@ impl UpdateChatIsBlocked {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatIsBlockedBuilder {
         let mut inner = UpdateChatIsBlocked::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1561,6 +1877,10 @@ pub struct UpdateChatIsMarkedAsUnread {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// New value of is_marked_as_unread
@@ -1584,6 +1904,12 @@ impl UpdateChatIsMarkedAsUnread {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatIsMarkedAsUnreadBuilder {
         let mut inner = UpdateChatIsMarkedAsUnread::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1641,6 +1967,10 @@ pub struct UpdateChatLastMessage {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new last message in the chat; may be null
@@ -1666,6 +1996,12 @@ impl UpdateChatLastMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatLastMessageBuilder {
         let mut inner = UpdateChatLastMessage::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1732,6 +2068,10 @@ pub struct UpdateChatNotificationSettings {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new notification settings
@@ -1755,6 +2095,12 @@ impl UpdateChatNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatNotificationSettingsBuilder {
         let mut inner = UpdateChatNotificationSettings::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1815,6 +2161,10 @@ pub struct UpdateChatOnlineMemberCount {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of the chat
     chat_id: i64,
     /// New number of online members in the chat, or 0 if unknown
@@ -1838,6 +2188,12 @@ impl UpdateChatOnlineMemberCount {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatOnlineMemberCountBuilder {
         let mut inner = UpdateChatOnlineMemberCount::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1895,6 +2251,10 @@ pub struct UpdateChatPermissions {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new chat permissions
@@ -1918,6 +2278,12 @@ impl UpdateChatPermissions {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatPermissionsBuilder {
         let mut inner = UpdateChatPermissions::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -1975,6 +2341,10 @@ pub struct UpdateChatPhoto {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new chat photo; may be null
@@ -1998,6 +2368,12 @@ impl UpdateChatPhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatPhotoBuilder {
         let mut inner = UpdateChatPhoto::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2055,6 +2431,10 @@ pub struct UpdateChatPosition {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// New chat position. If new order is 0, then the chat needs to be removed from the list
@@ -2078,6 +2458,12 @@ impl UpdateChatPosition {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatPositionBuilder {
         let mut inner = UpdateChatPosition::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2135,6 +2521,10 @@ pub struct UpdateChatReadInbox {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Identifier of the last read incoming message
@@ -2160,6 +2550,12 @@ impl UpdateChatReadInbox {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatReadInboxBuilder {
         let mut inner = UpdateChatReadInbox::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2226,6 +2622,10 @@ pub struct UpdateChatReadOutbox {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Identifier of last read outgoing message
@@ -2249,6 +2649,12 @@ impl UpdateChatReadOutbox {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatReadOutboxBuilder {
         let mut inner = UpdateChatReadOutbox::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2306,6 +2712,10 @@ pub struct UpdateChatReplyMarkup {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
@@ -2329,6 +2739,12 @@ impl UpdateChatReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatReplyMarkupBuilder {
         let mut inner = UpdateChatReplyMarkup::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2386,6 +2802,10 @@ pub struct UpdateChatTitle {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The new chat title
@@ -2409,6 +2829,12 @@ impl UpdateChatTitle {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatTitleBuilder {
         let mut inner = UpdateChatTitle::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2466,6 +2892,10 @@ pub struct UpdateChatUnreadMentionCount {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The number of unread mention messages left in the chat
@@ -2489,6 +2919,12 @@ impl UpdateChatUnreadMentionCount {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateChatUnreadMentionCountBuilder {
         let mut inner = UpdateChatUnreadMentionCount::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2546,6 +2982,10 @@ pub struct UpdateConnectionState {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new connection state
 
     #[serde(skip_serializing_if = "ConnectionState::_is_default")]
@@ -2569,6 +3009,12 @@ impl UpdateConnectionState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateConnectionStateBuilder {
         let mut inner = UpdateConnectionState::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2617,6 +3063,10 @@ pub struct UpdateDeleteMessages {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Identifiers of the deleted messages
@@ -2644,6 +3094,12 @@ impl UpdateDeleteMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateDeleteMessagesBuilder {
         let mut inner = UpdateDeleteMessages::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2719,6 +3175,10 @@ pub struct UpdateDiceEmojis {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new list of supported dice emojis
     emojis: Vec<String>,
 }
@@ -2740,6 +3200,12 @@ impl UpdateDiceEmojis {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateDiceEmojisBuilder {
         let mut inner = UpdateDiceEmojis::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2788,6 +3254,10 @@ pub struct UpdateFavoriteStickers {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new list of file identifiers of favorite stickers
     sticker_ids: Vec<i32>,
 }
@@ -2809,6 +3279,12 @@ impl UpdateFavoriteStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateFavoriteStickersBuilder {
         let mut inner = UpdateFavoriteStickers::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2857,6 +3333,10 @@ pub struct UpdateFile {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the file
     file: File,
 }
@@ -2878,6 +3358,12 @@ impl UpdateFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateFileBuilder {
         let mut inner = UpdateFile::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -2926,6 +3412,10 @@ pub struct UpdateFileGenerationStart {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique identifier for the generation process
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -2955,6 +3445,12 @@ impl UpdateFileGenerationStart {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateFileGenerationStartBuilder {
         let mut inner = UpdateFileGenerationStart::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3030,6 +3526,10 @@ pub struct UpdateFileGenerationStop {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique identifier for the generation process
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -3053,6 +3553,12 @@ impl UpdateFileGenerationStop {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateFileGenerationStopBuilder {
         let mut inner = UpdateFileGenerationStop::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3101,6 +3607,10 @@ pub struct UpdateHavePendingNotifications {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// True, if there are some delayed notification updates, which will be sent soon
     have_delayed_notifications: bool,
     /// True, if there can be some yet unreceived notifications, which are being fetched from the server
@@ -3124,6 +3634,12 @@ impl UpdateHavePendingNotifications {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateHavePendingNotificationsBuilder {
         let mut inner = UpdateHavePendingNotifications::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3184,6 +3700,10 @@ pub struct UpdateInstalledStickerSets {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// True, if the list of installed mask sticker sets was updated
     is_masks: bool,
     /// The new list of installed ordinary sticker sets
@@ -3209,6 +3729,12 @@ impl UpdateInstalledStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateInstalledStickerSetsBuilder {
         let mut inner = UpdateInstalledStickerSets::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3266,6 +3792,10 @@ pub struct UpdateLanguagePackStrings {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Localization target to which the language pack belongs
     localization_target: String,
     /// Identifier of the updated language pack
@@ -3291,6 +3821,12 @@ impl UpdateLanguagePackStrings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateLanguagePackStringsBuilder {
         let mut inner = UpdateLanguagePackStrings::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3357,6 +3893,10 @@ pub struct UpdateMessageContent {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Message identifier
@@ -3384,6 +3924,12 @@ impl UpdateMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageContentBuilder {
         let mut inner = UpdateMessageContent::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3450,6 +3996,10 @@ pub struct UpdateMessageContentOpened {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Message identifier
@@ -3473,6 +4023,12 @@ impl UpdateMessageContentOpened {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageContentOpenedBuilder {
         let mut inner = UpdateMessageContentOpened::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3530,6 +4086,10 @@ pub struct UpdateMessageEdited {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Message identifier
@@ -3557,6 +4117,12 @@ impl UpdateMessageEdited {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageEditedBuilder {
         let mut inner = UpdateMessageEdited::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3632,6 +4198,10 @@ pub struct UpdateMessageInteractionInfo {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Message identifier
@@ -3657,6 +4227,12 @@ impl UpdateMessageInteractionInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageInteractionInfoBuilder {
         let mut inner = UpdateMessageInteractionInfo::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3726,6 +4302,10 @@ pub struct UpdateMessageIsPinned {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// The message identifier
@@ -3751,6 +4331,12 @@ impl UpdateMessageIsPinned {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageIsPinnedBuilder {
         let mut inner = UpdateMessageIsPinned::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3817,6 +4403,10 @@ pub struct UpdateMessageLiveLocationViewed {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of the chat with the live location message
     chat_id: i64,
     /// Identifier of the message with live location
@@ -3840,6 +4430,12 @@ impl UpdateMessageLiveLocationViewed {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageLiveLocationViewedBuilder {
         let mut inner = UpdateMessageLiveLocationViewed::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3897,6 +4493,10 @@ pub struct UpdateMessageMentionRead {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// Message identifier
@@ -3922,6 +4522,12 @@ impl UpdateMessageMentionRead {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageMentionReadBuilder {
         let mut inner = UpdateMessageMentionRead::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -3988,6 +4594,10 @@ pub struct UpdateMessageSendAcknowledged {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The chat identifier of the sent message
     chat_id: i64,
     /// A temporary message identifier
@@ -4011,6 +4621,12 @@ impl UpdateMessageSendAcknowledged {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageSendAcknowledgedBuilder {
         let mut inner = UpdateMessageSendAcknowledged::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4068,6 +4684,10 @@ pub struct UpdateMessageSendFailed {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Contains information about the message which failed to send
     message: Message,
     /// The previous temporary message identifier
@@ -4095,6 +4715,12 @@ impl UpdateMessageSendFailed {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageSendFailedBuilder {
         let mut inner = UpdateMessageSendFailed::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4170,6 +4796,10 @@ pub struct UpdateMessageSendSucceeded {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Information about the sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change
     message: Message,
     /// The previous temporary message identifier
@@ -4193,6 +4823,12 @@ impl UpdateMessageSendSucceeded {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateMessageSendSucceededBuilder {
         let mut inner = UpdateMessageSendSucceeded::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4250,6 +4886,10 @@ pub struct UpdateNewCallSignalingData {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The call identifier
     call_id: i32,
     /// The data
@@ -4273,6 +4913,12 @@ impl UpdateNewCallSignalingData {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewCallSignalingDataBuilder {
         let mut inner = UpdateNewCallSignalingData::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4330,6 +4976,10 @@ pub struct UpdateNewCallbackQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -4367,6 +5017,12 @@ impl UpdateNewCallbackQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewCallbackQueryBuilder {
         let mut inner = UpdateNewCallbackQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4460,6 +5116,10 @@ pub struct UpdateNewChat {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The chat
     chat: Chat,
 }
@@ -4481,6 +5141,12 @@ impl UpdateNewChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewChatBuilder {
         let mut inner = UpdateNewChat::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4529,6 +5195,10 @@ pub struct UpdateNewChosenInlineResult {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of the user who sent the query
     sender_user_id: i32,
     /// User location; may be null
@@ -4558,6 +5228,12 @@ impl UpdateNewChosenInlineResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewChosenInlineResultBuilder {
         let mut inner = UpdateNewChosenInlineResult::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4642,6 +5318,10 @@ pub struct UpdateNewCustomEvent {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// A JSON-serialized event
     event: String,
 }
@@ -4663,6 +5343,12 @@ impl UpdateNewCustomEvent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewCustomEventBuilder {
         let mut inner = UpdateNewCustomEvent::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4711,6 +5397,10 @@ pub struct UpdateNewCustomQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -4738,6 +5428,12 @@ impl UpdateNewCustomQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewCustomQueryBuilder {
         let mut inner = UpdateNewCustomQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4804,6 +5500,10 @@ pub struct UpdateNewInlineCallbackQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -4839,6 +5539,12 @@ impl UpdateNewInlineCallbackQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewInlineCallbackQueryBuilder {
         let mut inner = UpdateNewInlineCallbackQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -4923,6 +5629,10 @@ pub struct UpdateNewInlineQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -4954,6 +5664,12 @@ impl UpdateNewInlineQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewInlineQueryBuilder {
         let mut inner = UpdateNewInlineQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5038,6 +5754,10 @@ pub struct UpdateNewMessage {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new message
     message: Message,
 }
@@ -5059,6 +5779,12 @@ impl UpdateNewMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewMessageBuilder {
         let mut inner = UpdateNewMessage::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5107,6 +5833,10 @@ pub struct UpdateNewPreCheckoutQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -5142,6 +5872,12 @@ impl UpdateNewPreCheckoutQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewPreCheckoutQueryBuilder {
         let mut inner = UpdateNewPreCheckoutQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5244,6 +5980,10 @@ pub struct UpdateNewShippingQuery {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique query identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -5273,6 +6013,12 @@ impl UpdateNewShippingQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNewShippingQueryBuilder {
         let mut inner = UpdateNewShippingQuery::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5348,6 +6094,10 @@ pub struct UpdateNotification {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique notification group identifier
     notification_group_id: i32,
     /// Changed notification
@@ -5371,6 +6121,12 @@ impl UpdateNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNotificationBuilder {
         let mut inner = UpdateNotification::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5428,6 +6184,10 @@ pub struct UpdateNotificationGroup {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique notification group identifier
     notification_group_id: i32,
     /// New type of the notification group
@@ -5466,6 +6226,12 @@ impl UpdateNotificationGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateNotificationGroupBuilder {
         let mut inner = UpdateNotificationGroup::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5580,6 +6346,10 @@ pub struct UpdateOption {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The option name
     name: String,
     /// The new option value
@@ -5605,6 +6375,12 @@ impl UpdateOption {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateOptionBuilder {
         let mut inner = UpdateOption::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5662,6 +6438,10 @@ pub struct UpdatePoll {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the poll
     poll: Poll,
 }
@@ -5683,6 +6463,12 @@ impl UpdatePoll {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdatePollBuilder {
         let mut inner = UpdatePoll::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5731,6 +6517,10 @@ pub struct UpdatePollAnswer {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Unique poll identifier
 
     #[serde(deserialize_with = "super::_common::number_from_string")]
@@ -5758,6 +6548,12 @@ impl UpdatePollAnswer {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdatePollAnswerBuilder {
         let mut inner = UpdatePollAnswer::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5824,6 +6620,10 @@ pub struct UpdateRecentStickers {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated
     is_attached: bool,
     /// The new list of file identifiers of recently used stickers
@@ -5847,6 +6647,12 @@ impl UpdateRecentStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateRecentStickersBuilder {
         let mut inner = UpdateRecentStickers::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5904,6 +6710,10 @@ pub struct UpdateSavedAnimations {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new list of file identifiers of saved animations
     animation_ids: Vec<i32>,
 }
@@ -5925,6 +6735,12 @@ impl UpdateSavedAnimations {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSavedAnimationsBuilder {
         let mut inner = UpdateSavedAnimations::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -5973,6 +6789,10 @@ pub struct UpdateScopeNotificationSettings {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Types of chats for which notification settings were updated
 
     #[serde(skip_serializing_if = "NotificationSettingsScope::_is_default")]
@@ -5998,6 +6818,12 @@ impl UpdateScopeNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateScopeNotificationSettingsBuilder {
         let mut inner = UpdateScopeNotificationSettings::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6058,6 +6884,10 @@ pub struct UpdateSecretChat {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the secret chat
     secret_chat: SecretChat,
 }
@@ -6079,6 +6909,12 @@ impl UpdateSecretChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSecretChatBuilder {
         let mut inner = UpdateSecretChat::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6127,6 +6963,10 @@ pub struct UpdateSelectedBackground {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// True, if background for dark theme has changed
     for_dark_theme: bool,
     /// The new selected background; may be null
@@ -6150,6 +6990,12 @@ impl UpdateSelectedBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSelectedBackgroundBuilder {
         let mut inner = UpdateSelectedBackground::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6207,6 +7053,10 @@ pub struct UpdateServiceNotification {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Notification type. If type begins with "AUTH_KEY_DROP_", then two buttons "Cancel" and "Log out" should be shown under notification; if user presses the second, all local data should be destroyed using Destroy method
 
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -6234,6 +7084,12 @@ impl UpdateServiceNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateServiceNotificationBuilder {
         let mut inner = UpdateServiceNotification::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6291,6 +7147,10 @@ pub struct UpdateStickerSet {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The sticker set
     sticker_set: StickerSet,
 }
@@ -6312,6 +7172,12 @@ impl UpdateStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateStickerSetBuilder {
         let mut inner = UpdateStickerSet::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6360,6 +7226,10 @@ pub struct UpdateSuggestedActions {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Added suggested actions
     added_actions: Vec<SuggestedAction>,
     /// Removed suggested actions
@@ -6383,6 +7253,12 @@ impl UpdateSuggestedActions {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSuggestedActionsBuilder {
         let mut inner = UpdateSuggestedActions::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6440,6 +7316,10 @@ pub struct UpdateSupergroup {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the supergroup
     supergroup: Supergroup,
 }
@@ -6461,6 +7341,12 @@ impl UpdateSupergroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSupergroupBuilder {
         let mut inner = UpdateSupergroup::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6509,6 +7395,10 @@ pub struct UpdateSupergroupFullInfo {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of the supergroup or channel
     supergroup_id: i32,
     /// New full information about the supergroup
@@ -6532,6 +7422,12 @@ impl UpdateSupergroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateSupergroupFullInfoBuilder {
         let mut inner = UpdateSupergroupFullInfo::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6592,6 +7488,10 @@ pub struct UpdateTermsOfService {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Identifier of the terms of service
     terms_of_service_id: String,
     /// The new terms of service
@@ -6615,6 +7515,12 @@ impl UpdateTermsOfService {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateTermsOfServiceBuilder {
         let mut inner = UpdateTermsOfService::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6672,6 +7578,10 @@ pub struct UpdateTrendingStickerSets {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The prefix of the list of trending sticker sets with the newest trending sticker sets
     sticker_sets: StickerSets,
 }
@@ -6693,6 +7603,12 @@ impl UpdateTrendingStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateTrendingStickerSetsBuilder {
         let mut inner = UpdateTrendingStickerSets::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6741,6 +7657,10 @@ pub struct UpdateUnreadChatCount {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The chat list with changed number of unread messages
 
     #[serde(skip_serializing_if = "ChatList::_is_default")]
@@ -6774,6 +7694,12 @@ impl UpdateUnreadChatCount {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUnreadChatCountBuilder {
         let mut inner = UpdateUnreadChatCount::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6870,6 +7796,10 @@ pub struct UpdateUnreadMessageCount {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The chat list with changed number of unread messages
 
     #[serde(skip_serializing_if = "ChatList::_is_default")]
@@ -6897,6 +7827,12 @@ impl UpdateUnreadMessageCount {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUnreadMessageCountBuilder {
         let mut inner = UpdateUnreadMessageCount::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -6963,6 +7899,10 @@ pub struct UpdateUser {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// New data about the user
     user: User,
 }
@@ -6984,6 +7924,12 @@ impl UpdateUser {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUserBuilder {
         let mut inner = UpdateUser::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -7032,6 +7978,10 @@ pub struct UpdateUserChatAction {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// Chat identifier
     chat_id: i64,
     /// If not 0, a message thread identifier in which the action was performed
@@ -7061,6 +8011,12 @@ impl UpdateUserChatAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUserChatActionBuilder {
         let mut inner = UpdateUserChatAction::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -7136,6 +8092,10 @@ pub struct UpdateUserFullInfo {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// User identifier
     user_id: i32,
     /// New full information about the user
@@ -7159,6 +8119,12 @@ impl UpdateUserFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUserFullInfoBuilder {
         let mut inner = UpdateUserFullInfo::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -7216,6 +8182,10 @@ pub struct UpdateUserPrivacySettingRules {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The privacy setting
 
     #[serde(skip_serializing_if = "UserPrivacySetting::_is_default")]
@@ -7241,6 +8211,12 @@ impl UpdateUserPrivacySettingRules {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUserPrivacySettingRulesBuilder {
         let mut inner = UpdateUserPrivacySettingRules::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -7298,6 +8274,10 @@ pub struct UpdateUserStatus {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// User identifier
     user_id: i32,
     /// New status of the user
@@ -7323,6 +8303,12 @@ impl UpdateUserStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUserStatusBuilder {
         let mut inner = UpdateUserStatus::default();
         inner.extra = Some(Uuid::new_v4().to_string());
@@ -7380,6 +8366,10 @@ pub struct UpdateUsersNearby {
     extra: Option<String>,
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
+    /// Fields which are not known to this version of the library
+    #[cfg(feature = "strict-deserialization")]
+    #[serde(flatten)]
+    extra_fields: HashMap<String, serde_json::Value>,
     /// The new list of users nearby
     users_nearby: Vec<ChatNearby>,
 }
@@ -7401,6 +8391,12 @@ impl UpdateUsersNearby {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
+
+    /// Returns fields received from TDLib but not known to this version of the library.
+    #[cfg(feature = "strict-deserialization")]
+    pub fn extra_fields(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra_fields
+    }
     pub fn builder() -> RTDUpdateUsersNearbyBuilder {
         let mut inner = UpdateUsersNearby::default();
         inner.extra = Some(Uuid::new_v4().to_string());